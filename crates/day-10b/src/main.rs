@@ -1,16 +1,20 @@
 use std::{ops::Add, str::FromStr};
 
 use anyhow::Context;
-use utilities::maps::{self, Grid, ALL_FOUR_COMPASS_DIRECTIONS};
+use utilities::maps::{self, Grid};
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-10.txt"
-    ));
+    )));
 
     let map = LavaMap::from_str(input).unwrap();
-    println!("{}", map.trailhead_score_sum());
+    let timer = timer.parsed();
+    let answer = map.trailhead_score_sum();
+    timer.solved();
+    println!("{answer}");
 }
 
 const MAX_COORDINATE: u16 = 52;
@@ -57,12 +61,9 @@ impl LavaMap {
         if current_height == 9 {
             return 1;
         }
-        ALL_FOUR_COMPASS_DIRECTIONS
-            .iter()
-            .copied()
-            .filter_map(|direction| point.shift(direction))
-            .filter(|new_point| self.height_at(*new_point) == current_height + 1)
-            .map(|new_point| self.routes_to_peak_from(new_point))
+        self.0
+            .passable_neighbours(point, |height| *height == current_height + 1)
+            .map(|(_, new_point, _)| self.routes_to_peak_from(new_point))
             .sum()
     }
 
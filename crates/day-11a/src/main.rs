@@ -1,42 +1,50 @@
+use utilities::{digits, parallel};
+
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-11.txt"
-    ));
+    )));
 
-    let mut stones: Vec<Stone> = input
+    let stones: Vec<Stone> = input
         .split_ascii_whitespace()
         .map(|digits| Stone(digits.parse().unwrap()))
         .collect();
+    let timer = timer.parsed();
 
-    for _ in 0..25 {
-        stones = stones
-            .into_iter()
-            .flat_map(Stone::blink)
-            .flatten()
-            .collect();
-    }
+    // Stones never affect each other, so each one can be blinked at on its own thread.
+    let answer: usize = parallel::map(&stones, |stone| stone.stones_after_blinks(25))
+        .into_iter()
+        .sum();
+    timer.solved();
 
-    println!("{}", stones.len());
+    println!("{answer}");
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct Stone(u64);
 
 impl Stone {
+    fn stones_after_blinks(self, blinks: usize) -> usize {
+        let mut stones = vec![self];
+        for _ in 0..blinks {
+            stones = stones
+                .into_iter()
+                .flat_map(Stone::blink)
+                .flatten()
+                .collect();
+        }
+        stones.len()
+    }
+
     fn blink(self) -> [Option<Self>; 2] {
         if self.0 == 0 {
             [Some(Stone(1)), None]
+        } else if let Some((first, second)) = digits::split_halves(self.0) {
+            [Some(Stone(first)), Some(Stone(second))]
         } else {
-            let num_digits = self.0.ilog10() + 1;
-            if num_digits % 2 == 0 {
-                let multiplier = 10u64.pow(num_digits / 2);
-                let first_stone = Stone(self.0 / multiplier);
-                let second_stone = Stone(self.0 - first_stone.0 * multiplier);
-                [Some(first_stone), Some(second_stone)]
-            } else {
-                [Some(Stone(self.0 * 2024)), None]
-            }
+            [Some(Stone(self.0 * 2024)), None]
         }
     }
 }
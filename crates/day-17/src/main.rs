@@ -0,0 +1,479 @@
+use std::{fmt, ops::Deref, str::FromStr};
+
+use anyhow::{anyhow, bail, Context};
+use itertools::Itertools;
+use utilities::parse;
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-17.txt"
+    )));
+
+    let computer: Computer = input.parse()?;
+    let timer = timer.parsed();
+
+    match std::env::args().nth(1).as_deref() {
+        None | Some("a") => {
+            let answer = computer.run()?.iter().join(",");
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("b") => {
+            let answer = computer
+                .find_quine()
+                .context("Expected some value of register A to make the program output itself")?;
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("disassemble") => print!("{}", computer.program.disassemble()),
+        Some("trace") => {
+            for step in computer.execute() {
+                println!("{}", step?);
+            }
+        }
+        Some(other) => {
+            bail!("Unknown command {other:?}: expected one of `a`, `b`, `disassemble` or `trace`")
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+struct Computer {
+    registers: Registers,
+    program: Program,
+}
+
+impl Computer {
+    fn execute(&self) -> Execution<'_> {
+        Execution {
+            program: &self.program,
+            registers: self.registers,
+            instruction_pointer: 0,
+        }
+    }
+
+    fn run(&self) -> anyhow::Result<Vec<u8>> {
+        self.execute().filter_map_ok(|step| step.output).collect()
+    }
+
+    fn with_register_a(&self, a: u64) -> Self {
+        Self {
+            registers: Registers {
+                a,
+                ..self.registers
+            },
+            program: self.program.clone(),
+        }
+    }
+
+    /// Find the lowest value for register A that makes the program output a copy of itself.
+    ///
+    /// This relies on the program (like every puzzle input) being a single loop
+    /// that outputs one value and shifts three bits off register A on each iteration.
+    /// The last value output therefore only depends on the highest octal digit of A,
+    /// the second-to-last value only depends on the highest two octal digits, and so on,
+    /// so we can build A up one octal digit at a time by working backwards through the program.
+    fn find_quine(&self) -> Option<u64> {
+        self.extend_quine(0, 0)
+    }
+
+    fn extend_quine(&self, a_prefix: u64, digits_found: usize) -> Option<u64> {
+        if digits_found == self.program.len() {
+            return Some(a_prefix);
+        }
+        let expected_output = &self.program[self.program.len() - digits_found - 1..];
+        (0..8)
+            .map(|digit| a_prefix << 3 | digit)
+            .filter(|candidate| {
+                self.with_register_a(*candidate)
+                    .run()
+                    .is_ok_and(|output| output == expected_output)
+            })
+            .find_map(|candidate| self.extend_quine(candidate, digits_found + 1))
+    }
+}
+
+impl FromStr for Computer {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [registers_input, program_input] = parse::sections(s)?;
+
+        Ok(Self {
+            registers: registers_input.parse()?,
+            program: program_input.parse()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Registers { a, b, c } = self;
+        write!(f, "A={a} B={b} C={c}")
+    }
+}
+
+impl FromStr for Registers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut registers = Registers::default();
+
+        for line in s.lines() {
+            let (name, value) = parse::pair(line, ": ")?;
+            let name = name
+                .strip_prefix("Register ")
+                .with_context(|| format!("Expected a line like `Register A: 0`, got {line:?}"))?;
+            let register = match name {
+                "A" => &mut registers.a,
+                "B" => &mut registers.b,
+                "C" => &mut registers.c,
+                _ => bail!("Unknown register {name:?}"),
+            };
+            *register = parse::item(value)?;
+        }
+
+        Ok(registers)
+    }
+}
+
+/// A program for the three-bit computer: every element is a value in the range `0..8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Program(Box<[u8]>);
+
+impl Program {
+    fn instruction_at(&self, instruction_pointer: usize) -> Option<Instruction> {
+        let opcode = *self.get(instruction_pointer)?;
+        let operand = *self.get(instruction_pointer + 1)?;
+        Some(Instruction {
+            opcode: Opcode::try_from(opcode).ok()?,
+            operand,
+        })
+    }
+
+    fn disassemble(&self) -> Disassembly<'_> {
+        Disassembly(self)
+    }
+}
+
+impl Deref for Program {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for Program {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .trim_end()
+            .strip_prefix("Program: ")
+            .context("Expected the program to start with `Program: `")?;
+
+        let values: Vec<u8> = parse::separated(values, ",")?;
+        if let Some(value) = values.iter().find(|value| **value >= 8) {
+            bail!("Expected every value in the program to be <8, got {value}");
+        }
+        Ok(Self(values.into_boxed_slice()))
+    }
+}
+
+/// Human-readable listing of a [`Program`], one instruction per line.
+struct Disassembly<'a>(&'a Program);
+
+impl fmt::Display for Disassembly<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Disassembly(program) = self;
+        for instruction_pointer in (0..program.len()).step_by(2) {
+            match program.instruction_at(instruction_pointer) {
+                Some(instruction) => writeln!(f, "{instruction_pointer:>3}: {instruction}")?,
+                None => writeln!(
+                    f,
+                    "{instruction_pointer:>3}: <trailing value {}>",
+                    program[instruction_pointer]
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    const fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Adv => "adv",
+            Self::Bxl => "bxl",
+            Self::Bst => "bst",
+            Self::Jnz => "jnz",
+            Self::Bxc => "bxc",
+            Self::Out => "out",
+            Self::Bdv => "bdv",
+            Self::Cdv => "cdv",
+        }
+    }
+}
+
+impl TryFrom<u8> for Opcode {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Adv),
+            1 => Ok(Self::Bxl),
+            2 => Ok(Self::Bst),
+            3 => Ok(Self::Jnz),
+            4 => Ok(Self::Bxc),
+            5 => Ok(Self::Out),
+            6 => Ok(Self::Bdv),
+            7 => Ok(Self::Cdv),
+            _ => Err(anyhow!("Unknown opcode {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComboOperand {
+    Literal(u8),
+    A,
+    B,
+    C,
+}
+
+impl ComboOperand {
+    const fn value(self, registers: &Registers) -> u64 {
+        match self {
+            Self::Literal(value) => value as u64,
+            Self::A => registers.a,
+            Self::B => registers.b,
+            Self::C => registers.c,
+        }
+    }
+}
+
+impl TryFrom<u8> for ComboOperand {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0..=3 => Ok(Self::Literal(value)),
+            4 => Ok(Self::A),
+            5 => Ok(Self::B),
+            6 => Ok(Self::C),
+            _ => Err(anyhow!("Combo operand {value} is reserved")),
+        }
+    }
+}
+
+impl fmt::Display for ComboOperand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{value}"),
+            Self::A => f.write_str("A"),
+            Self::B => f.write_str("B"),
+            Self::C => f.write_str("C"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    opcode: Opcode,
+    operand: u8,
+}
+
+impl Instruction {
+    fn combo_operand(self) -> anyhow::Result<ComboOperand> {
+        ComboOperand::try_from(self.operand)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Instruction { opcode, operand } = *self;
+        let combo = self
+            .combo_operand()
+            .map_or_else(|_| String::from("<reserved>"), |combo| combo.to_string());
+        let pseudocode = match opcode {
+            Opcode::Adv => format!("A = A >> {combo}"),
+            Opcode::Bxl => format!("B = B ^ {operand}"),
+            Opcode::Bst => format!("B = {combo} % 8"),
+            Opcode::Jnz => format!("if A != 0: jump {operand}"),
+            Opcode::Bxc => String::from("B = B ^ C"),
+            Opcode::Out => format!("output {combo} % 8"),
+            Opcode::Bdv => format!("B = A >> {combo}"),
+            Opcode::Cdv => format!("C = A >> {combo}"),
+        };
+        write!(f, "{} {operand}    {pseudocode}", opcode.mnemonic())
+    }
+}
+
+/// A program being run on the three-bit computer, yielding one [`Step`] per instruction executed.
+struct Execution<'a> {
+    program: &'a Program,
+    registers: Registers,
+    instruction_pointer: usize,
+}
+
+impl Execution<'_> {
+    fn execute(&mut self, instruction: Instruction) -> anyhow::Result<Option<u8>> {
+        let Instruction { opcode, operand } = instruction;
+        let registers = &mut self.registers;
+        let shift_a = |registers: &Registers| -> anyhow::Result<u64> {
+            let shift = instruction.combo_operand()?.value(registers);
+            Ok(u32::try_from(shift)
+                .ok()
+                .and_then(|shift| registers.a.checked_shr(shift))
+                .unwrap_or(0))
+        };
+
+        self.instruction_pointer += 2;
+
+        match opcode {
+            Opcode::Adv => registers.a = shift_a(registers)?,
+            Opcode::Bxl => registers.b ^= u64::from(operand),
+            Opcode::Bst => registers.b = instruction.combo_operand()?.value(registers) % 8,
+            Opcode::Jnz => {
+                if registers.a != 0 {
+                    self.instruction_pointer = usize::from(operand);
+                }
+            }
+            Opcode::Bxc => registers.b ^= registers.c,
+            Opcode::Out => {
+                let value = instruction.combo_operand()?.value(registers) % 8;
+                return Ok(Some(u8::try_from(value)?));
+            }
+            Opcode::Bdv => registers.b = shift_a(registers)?,
+            Opcode::Cdv => registers.c = shift_a(registers)?,
+        }
+
+        Ok(None)
+    }
+}
+
+impl Iterator for Execution<'_> {
+    type Item = anyhow::Result<Step>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instruction_pointer = self.instruction_pointer;
+        let instruction = self.program.instruction_at(instruction_pointer)?;
+        Some(self.execute(instruction).map(|output| Step {
+            instruction_pointer,
+            instruction,
+            registers: self.registers,
+            output,
+        }))
+    }
+}
+
+/// A single instruction executed by the three-bit computer,
+/// along with the state of the registers after it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    instruction_pointer: usize,
+    instruction: Instruction,
+    registers: Registers,
+    output: Option<u8>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Step {
+            instruction_pointer,
+            instruction,
+            registers,
+            output,
+        } = self;
+        let instruction = instruction.to_string();
+        write!(f, "{instruction_pointer:>3}: {instruction:<30} {registers}")?;
+        if let Some(output) = output {
+            write!(f, " -> {output}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn computer(a: u64, b: u64, c: u64, program: &[u8]) -> Computer {
+        Computer {
+            registers: Registers { a, b, c },
+            program: Program(program.into()),
+        }
+    }
+
+    fn final_registers(computer: &Computer) -> Registers {
+        computer
+            .execute()
+            .map(|step| step.unwrap().registers)
+            .last()
+            .unwrap()
+    }
+
+    #[test]
+    fn small_examples() {
+        assert_eq!(final_registers(&computer(0, 0, 9, &[2, 6])).b, 1);
+        assert_eq!(
+            computer(10, 0, 0, &[5, 0, 5, 1, 5, 4]).run().unwrap(),
+            [0, 1, 2]
+        );
+        let program = computer(2024, 0, 0, &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(program.run().unwrap(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(final_registers(&program).a, 0);
+        assert_eq!(final_registers(&computer(0, 29, 0, &[1, 7])).b, 26);
+        assert_eq!(final_registers(&computer(0, 2024, 43690, &[4, 0])).b, 44354);
+    }
+
+    #[test]
+    fn part_a() {
+        let input = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+        let computer: Computer = input.parse().unwrap();
+        assert_eq!(
+            computer.run().unwrap().iter().join(","),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+
+    #[test]
+    fn part_b() {
+        let computer = computer(2024, 0, 0, &[0, 3, 5, 4, 3, 0]);
+        assert_eq!(computer.find_quine(), Some(117_440));
+    }
+
+    #[test]
+    fn reserved_combo_operand_is_an_error() {
+        assert!(computer(0, 0, 0, &[2, 7]).run().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::Context;
+use rustc_hash::FxHashSet;
+use utilities::{
+    maps::{Grid, Point},
+    parse,
+};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-18.txt"
+    )));
+
+    let bytes = parse_input(input).unwrap();
+    let timer = timer.parsed();
+    let fallen = first_fallen(&bytes, BYTES_FALLEN).unwrap();
+    let memory_space = MemorySpace::<MAX_COORDINATE>::new(fallen).unwrap();
+    let answer = memory_space.shortest_path_length().unwrap();
+    timer.solved();
+    println!("{answer}");
+}
+
+const MAX_COORDINATE: u16 = 71;
+const BYTES_FALLEN: usize = 1024;
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<FallingByte>> {
+    parse::lines(input)
+}
+
+/// The first `count` bytes to fall, failing if the input doesn't have that many.
+fn first_fallen(bytes: &[FallingByte], count: usize) -> anyhow::Result<&[FallingByte]> {
+    bytes.get(..count).with_context(|| {
+        format!(
+            "Expected at least {count} falling bytes, got {}",
+            bytes.len()
+        )
+    })
+}
+
+/// The zero-based position a byte falls into, as given in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FallingByte {
+    x: usize,
+    y: usize,
+}
+
+impl FallingByte {
+    fn point<const SIZE: u16>(self) -> anyhow::Result<Point<SIZE>> {
+        Point::try_from((self.x + 1, self.y + 1))
+    }
+}
+
+impl FromStr for FallingByte {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::pair(s, ",")?;
+        Ok(Self {
+            x: parse::item(x)?,
+            y: parse::item(y)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Safe,
+    Corrupted,
+}
+
+/// A square memory space with sides of length `SIZE`.
+#[derive(Debug)]
+struct MemorySpace<const SIZE: u16>(Grid<SIZE, Cell>);
+
+impl<const SIZE: u16> MemorySpace<SIZE> {
+    fn new(fallen_bytes: &[FallingByte]) -> anyhow::Result<Self> {
+        let mut grid = Grid::default();
+        for x in 1..=usize::from(SIZE) {
+            for y in 1..=usize::from(SIZE) {
+                grid.insert(Point::try_from((x, y))?, Cell::Safe);
+            }
+        }
+        for byte in fallen_bytes {
+            grid.insert(byte.point()?, Cell::Corrupted);
+        }
+        Ok(Self(grid))
+    }
+
+    fn start() -> Point<SIZE> {
+        Point::try_from((1, 1)).unwrap()
+    }
+
+    fn exit() -> Point<SIZE> {
+        Point::try_from((usize::from(SIZE), usize::from(SIZE))).unwrap()
+    }
+
+    /// Breadth-first search from the top-left corner to the bottom-right corner,
+    /// returning the number of steps needed (or `None` if the exit cannot be reached).
+    fn shortest_path_length(&self) -> Option<usize> {
+        let start = Self::start();
+        let exit = Self::exit();
+        if [start, exit]
+            .iter()
+            .any(|point| self.0.get(point) != Some(&Cell::Safe))
+        {
+            return None;
+        }
+        let mut visited = FxHashSet::from_iter([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((point, steps)) = queue.pop_front() {
+            if point == exit {
+                return Some(steps);
+            }
+            for (_, neighbour, _) in self
+                .0
+                .passable_neighbours(point, |cell| *cell == Cell::Safe)
+            {
+                if visited.insert(neighbour) {
+                    queue.push_back((neighbour, steps + 1));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test() {
+        let bytes = parse_input(INPUT).unwrap();
+        let memory_space = MemorySpace::<7>::new(first_fallen(&bytes, 12).unwrap()).unwrap();
+        assert_eq!(memory_space.shortest_path_length(), Some(22));
+        assert!(first_fallen(&bytes, 26).is_err());
+    }
+
+    #[test]
+    fn corrupted_start_or_exit() {
+        let start = FallingByte { x: 0, y: 0 };
+        let exit = FallingByte { x: 6, y: 6 };
+        for byte in [start, exit] {
+            let memory_space = MemorySpace::<7>::new(&[byte]).unwrap();
+            assert_eq!(memory_space.shortest_path_length(), None);
+        }
+    }
+}
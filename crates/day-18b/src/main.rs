@@ -0,0 +1,201 @@
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+use rustc_hash::FxHashSet;
+use utilities::{
+    maps::{Grid, Point},
+    parse,
+};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-18.txt"
+    )));
+
+    let bytes = parse_input(input).unwrap();
+    let timer = timer.parsed();
+    let answer = first_blocking_byte::<MAX_COORDINATE>(&bytes)
+        .unwrap()
+        .expect("Expected the exit to be cut off once all bytes have fallen");
+    timer.solved();
+    println!("{answer}");
+}
+
+const MAX_COORDINATE: u16 = 71;
+
+/// Find the first byte that, once fallen, makes the exit unreachable from the start.
+///
+/// Whether the exit is reachable is monotonic in the number of bytes fallen,
+/// so we binary-search over the number of bytes rather than re-running the search after every byte.
+fn first_blocking_byte<const SIZE: u16>(
+    bytes: &[FallingByte],
+) -> anyhow::Result<Option<FallingByte>> {
+    let exit_reachable_after = |bytes_fallen: usize| -> anyhow::Result<bool> {
+        let memory_space = MemorySpace::<SIZE>::new(&bytes[..bytes_fallen])?;
+        Ok(memory_space.shortest_path_length().is_some())
+    };
+
+    if exit_reachable_after(bytes.len())? {
+        return Ok(None);
+    }
+
+    // Invariant: the exit is reachable after `low` bytes have fallen, but not after `high` have.
+    let mut low = 0;
+    let mut high = bytes.len();
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if exit_reachable_after(middle)? {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok(Some(bytes[high - 1]))
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<FallingByte>> {
+    parse::lines(input)
+}
+
+/// The zero-based position a byte falls into, as given in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FallingByte {
+    x: usize,
+    y: usize,
+}
+
+impl FallingByte {
+    fn point<const SIZE: u16>(self) -> anyhow::Result<Point<SIZE>> {
+        Point::try_from((self.x + 1, self.y + 1))
+    }
+}
+
+impl fmt::Display for FallingByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl FromStr for FallingByte {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::pair(s, ",")?;
+        Ok(Self {
+            x: parse::item(x)?,
+            y: parse::item(y)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Safe,
+    Corrupted,
+}
+
+/// A square memory space with sides of length `SIZE`.
+#[derive(Debug)]
+struct MemorySpace<const SIZE: u16>(Grid<SIZE, Cell>);
+
+impl<const SIZE: u16> MemorySpace<SIZE> {
+    fn new(fallen_bytes: &[FallingByte]) -> anyhow::Result<Self> {
+        let mut grid = Grid::default();
+        for x in 1..=usize::from(SIZE) {
+            for y in 1..=usize::from(SIZE) {
+                grid.insert(Point::try_from((x, y))?, Cell::Safe);
+            }
+        }
+        for byte in fallen_bytes {
+            grid.insert(byte.point()?, Cell::Corrupted);
+        }
+        Ok(Self(grid))
+    }
+
+    fn start() -> Point<SIZE> {
+        Point::try_from((1, 1)).unwrap()
+    }
+
+    fn exit() -> Point<SIZE> {
+        Point::try_from((usize::from(SIZE), usize::from(SIZE))).unwrap()
+    }
+
+    /// Breadth-first search from the top-left corner to the bottom-right corner,
+    /// returning the number of steps needed (or `None` if the exit cannot be reached).
+    fn shortest_path_length(&self) -> Option<usize> {
+        let start = Self::start();
+        let exit = Self::exit();
+        if [start, exit]
+            .iter()
+            .any(|point| self.0.get(point) != Some(&Cell::Safe))
+        {
+            return None;
+        }
+        let mut visited = FxHashSet::from_iter([start]);
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((point, steps)) = queue.pop_front() {
+            if point == exit {
+                return Some(steps);
+            }
+            for (_, neighbour, _) in self
+                .0
+                .passable_neighbours(point, |cell| *cell == Cell::Safe)
+            {
+                if visited.insert(neighbour) {
+                    queue.push_back((neighbour, steps + 1));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test() {
+        let bytes = parse_input(INPUT).unwrap();
+        let blocking_byte = first_blocking_byte::<7>(&bytes).unwrap().unwrap();
+        assert_eq!(blocking_byte.to_string(), "6,1");
+    }
+
+    #[test]
+    fn corrupted_start() {
+        let bytes = [FallingByte { x: 3, y: 3 }, FallingByte { x: 0, y: 0 }];
+        let blocking_byte = first_blocking_byte::<7>(&bytes).unwrap().unwrap();
+        assert_eq!(blocking_byte.to_string(), "0,0");
+    }
+}
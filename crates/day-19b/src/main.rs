@@ -0,0 +1,128 @@
+use anyhow::bail;
+use rustc_hash::FxHashMap;
+use utilities::{parse, trie::Trie};
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-19.txt"
+    )));
+
+    let (towels, designs) = parse_input(input)?;
+    let timer = timer.parsed();
+    let mut counter = ArrangementCounter::new(&towels);
+
+    match std::env::args().nth(1).as_deref() {
+        None => {
+            let answer: u64 = designs
+                .iter()
+                .map(|design| counter.arrangements(design))
+                .sum();
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("decompose") => {
+            for design in designs {
+                match counter.decomposition(design) {
+                    Some(towels) => println!("{design}: {}", towels.join(", ")),
+                    None => println!("{design}: impossible"),
+                }
+            }
+        }
+        Some(other) => bail!("Unknown command {other:?}: expected `decompose` or no arguments"),
+    }
+
+    Ok(())
+}
+
+fn parse_input(input: &str) -> anyhow::Result<(Trie, Vec<&str>)> {
+    let [towels_input, designs_input] = parse::sections(input)?;
+    let towels = towels_input.split(", ").collect();
+    let designs = designs_input.lines().collect();
+
+    Ok((towels, designs))
+}
+
+/// Counts the ways each design can be made from the available towels,
+/// memoizing the count for every suffix it sees along the way.
+///
+/// Designs share a lot of suffixes with each other, so the memo is kept across designs.
+#[derive(Debug)]
+struct ArrangementCounter<'a> {
+    towels: &'a Trie,
+    memo: FxHashMap<&'a str, u64>,
+}
+
+impl<'a> ArrangementCounter<'a> {
+    fn new(towels: &'a Trie) -> Self {
+        Self {
+            towels,
+            memo: FxHashMap::default(),
+        }
+    }
+
+    fn arrangements(&mut self, design: &'a str) -> u64 {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(count) = self.memo.get(design) {
+            return *count;
+        }
+        let towels = self.towels;
+        let count = towels
+            .prefixes_of(design)
+            .map(|towel| self.arrangements(&design[towel.len()..]))
+            .sum();
+        self.memo.insert(design, count);
+        count
+    }
+
+    fn is_possible(&mut self, design: &'a str) -> bool {
+        self.arrangements(design) > 0
+    }
+
+    /// Return one sequence of towels that makes up the design, if there is any.
+    fn decomposition(&mut self, design: &'a str) -> Option<Vec<&'a str>> {
+        let mut towels_used = vec![];
+        let mut remaining = design;
+        while !remaining.is_empty() {
+            let towels = self.towels;
+            let towel = towels
+                .prefixes_of(remaining)
+                .find(|towel| self.is_possible(&remaining[towel.len()..]))?;
+            towels_used.push(towel);
+            remaining = &remaining[towel.len()..];
+        }
+        Some(towels_used)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn test() {
+        let (towels, designs) = parse_input(INPUT).unwrap();
+        let mut counter = ArrangementCounter::new(&towels);
+        let arrangements: Vec<u64> = designs
+            .iter()
+            .map(|design| counter.arrangements(design))
+            .collect();
+        assert_eq!(arrangements, [2, 1, 4, 6, 0, 1, 2, 0]);
+        assert_eq!(arrangements.iter().sum::<u64>(), 16);
+    }
+}
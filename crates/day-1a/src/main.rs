@@ -1,20 +1,23 @@
+use utilities::parse;
+
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-1.txt"
-    ));
+    )));
 
     let mut left = Vec::<u32>::with_capacity(1000);
     let mut right = Vec::<u32>::with_capacity(1000);
 
     for line in input.lines() {
-        let mut split_whitespace = line.split_whitespace();
-        let mut next_number = || split_whitespace.next().unwrap().parse().unwrap();
-        left.push(next_number());
-        right.push(next_number());
-        assert_eq!(split_whitespace.next(), None);
+        let [left_number, right_number] = parse::columns(line).unwrap();
+        left.push(left_number);
+        right.push(right_number);
     }
 
+    let timer = timer.parsed();
+
     left.sort_unstable();
     right.sort_unstable();
 
@@ -23,6 +26,7 @@ fn main() {
         .zip(right)
         .map(|(left, right)| left.abs_diff(right))
         .sum();
+    timer.solved();
 
     println!("{answer}");
 }
@@ -0,0 +1,208 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+use rustc_hash::FxHashMap;
+use utilities::maps::{Grid, Point};
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-20.txt"
+    )));
+
+    let racetrack: Racetrack = input.parse()?;
+    let timer = timer.parsed();
+    let track = racetrack.track();
+
+    match std::env::args().nth(1).as_deref() {
+        None => {
+            let answer = cheat_savings(&track, MAX_CHEAT_DURATION)
+                .filter(|savings| *savings >= MINIMUM_SAVINGS)
+                .count();
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("histogram") => {
+            for (savings, count) in savings_histogram(&track, MAX_CHEAT_DURATION) {
+                println!("{count} cheats save {savings} picoseconds");
+            }
+        }
+        Some(other) => bail!("Unknown command {other:?}: expected `histogram` or no arguments"),
+    }
+
+    Ok(())
+}
+
+const MAX_COORDINATE: u16 = 141;
+const MAX_CHEAT_DURATION: u32 = 20;
+const MINIMUM_SAVINGS: u32 = 100;
+
+type RacePoint = Point<MAX_COORDINATE>;
+
+/// A point on the track, along with the number of picoseconds it takes to get there from the start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrackPosition {
+    point: RacePoint,
+    distance: u32,
+}
+
+/// Iterate over the picoseconds saved by every possible cheat lasting at most `max_cheat_duration`.
+///
+/// Since a cheat can pass through any walls, a cheat from `a` to `b` takes exactly
+/// the Manhattan distance between the two points, so there's no need to search through the walls.
+fn cheat_savings(
+    track: &[TrackPosition],
+    max_cheat_duration: u32,
+) -> impl Iterator<Item = u32> + '_ {
+    track.iter().enumerate().flat_map(move |(i, start)| {
+        track[i + 1..].iter().filter_map(move |end| {
+            let cheat_duration = start.point.manhattan_distance(end.point);
+            if cheat_duration > max_cheat_duration {
+                return None;
+            }
+            (end.distance - start.distance)
+                .checked_sub(cheat_duration)
+                .filter(|savings| *savings > 0)
+        })
+    })
+}
+
+/// Map each number of picoseconds saved to the number of cheats that save that much time.
+fn savings_histogram(track: &[TrackPosition], max_cheat_duration: u32) -> BTreeMap<u32, usize> {
+    let mut histogram = BTreeMap::new();
+    for savings in cheat_savings(track, max_cheat_duration) {
+        *histogram.entry(savings).or_default() += 1;
+    }
+    histogram
+}
+
+#[derive(Debug)]
+struct Racetrack {
+    map: Grid<MAX_COORDINATE, Cell>,
+    start: RacePoint,
+    end: RacePoint,
+}
+
+impl Racetrack {
+    /// Breadth-first search out from the start to find the distance of every point on the track.
+    ///
+    /// The result is sorted by distance from the start.
+    fn track(&self) -> Vec<TrackPosition> {
+        let mut distances = FxHashMap::from_iter([(self.start, 0)]);
+        let mut track = vec![];
+        let mut queue = VecDeque::from([(self.start, 0)]);
+
+        while let Some((point, distance)) = queue.pop_front() {
+            track.push(TrackPosition { point, distance });
+            if point == self.end {
+                continue;
+            }
+            for (_, neighbour, _) in self
+                .map
+                .passable_neighbours(point, |cell| *cell == Cell::Track)
+            {
+                if distances.contains_key(&neighbour) {
+                    continue;
+                }
+                distances.insert(neighbour, distance + 1);
+                queue.push_back((neighbour, distance + 1));
+            }
+        }
+
+        track
+    }
+}
+
+impl FromStr for Racetrack {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Grid::default();
+        let mut start = None;
+        let mut end = None;
+        for (zero_based_y, line) in s.lines().enumerate() {
+            for (zero_based_x, c) in line.char_indices() {
+                let point = RacePoint::try_from((zero_based_x + 1, zero_based_y + 1))?;
+                let cell = match c {
+                    '.' => Cell::Track,
+                    '#' => Cell::Wall,
+                    'S' => {
+                        start = Some(point);
+                        Cell::Track
+                    }
+                    'E' => {
+                        end = Some(point);
+                        Cell::Track
+                    }
+                    _ => bail!("Unexpected character '{c}'!"),
+                };
+                map.insert(point, cell);
+            }
+        }
+        Ok(Self {
+            map,
+            start: start.context("Expected to find 'S' somewhere in the map!")?,
+            end: end.context("Expected to find 'E' somewhere in the map!")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Track,
+    Wall,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn test() {
+        let racetrack: Racetrack = INPUT.parse().unwrap();
+        let track = racetrack.track();
+        assert_eq!(track.last().unwrap().distance, 84);
+        let mut histogram = savings_histogram(&track, 20);
+        histogram.retain(|savings, _| *savings >= 50);
+        assert_eq!(
+            histogram,
+            BTreeMap::from([
+                (50, 32),
+                (52, 31),
+                (54, 29),
+                (56, 39),
+                (58, 25),
+                (60, 23),
+                (62, 20),
+                (64, 19),
+                (66, 12),
+                (68, 14),
+                (70, 12),
+                (72, 22),
+                (74, 4),
+                (76, 3),
+            ])
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use rustc_hash::FxHashMap;
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-21.txt"
+    )));
+    // The door codes are used as they are, so there is nothing more to parse.
+    let timer = timer.parsed();
+
+    let mut args = std::env::args().skip(1);
+
+    match args.next().as_deref() {
+        None => {
+            let mut chain = KeypadChain::new(ROBOTS)?;
+            let answer = input
+                .lines()
+                .map(|code| chain.complexity(code))
+                .sum::<anyhow::Result<u64>>()?;
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("expand") => {
+            let robots = args.next().map_or(Ok(ROBOTS), |robots| robots.parse())?;
+            let mut chain = KeypadChain::new(robots)?;
+            for code in input.lines() {
+                println!("{code}: {}", chain.expand(code)?);
+            }
+        }
+        Some(other) => bail!("Unknown command {other:?}: expected `expand` or no arguments"),
+    }
+
+    Ok(())
+}
+
+/// The number of robots using directional keypads in between the human and the numeric keypad.
+const ROBOTS: usize = 2;
+
+const NUMERIC_KEYPAD: &str = "\
+789
+456
+123
+ 0A";
+
+const DIRECTIONAL_KEYPAD: &str = " ^A\n<v>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position {
+    row: i8,
+    column: i8,
+}
+
+/// A keypad layout: a small grid of buttons with a gap that the robot arm must never point at.
+#[derive(Debug)]
+struct Keypad {
+    buttons: FxHashMap<char, Position>,
+    gap: Position,
+}
+
+impl Keypad {
+    fn position(&self, button: char) -> anyhow::Result<Position> {
+        self.buttons
+            .get(&button)
+            .copied()
+            .with_context(|| format!("No button {button:?} on this keypad"))
+    }
+
+    /// The shortest button sequences on a directional keypad that move the arm
+    /// from one button to another on this keypad and then press it.
+    ///
+    /// Zig-zagging is never optimal (the robot upstream would have to move back and forth
+    /// between arrow keys), so the only candidates are "all horizontal moves first"
+    /// and "all vertical moves first", minus any that would pass over the gap.
+    fn paths(&self, from: char, to: char) -> anyhow::Result<Vec<String>> {
+        let from = self.position(from)?;
+        let to = self.position(to)?;
+
+        let column_delta = to.column - from.column;
+        let row_delta = to.row - from.row;
+        let horizontal_key = if column_delta < 0 { '<' } else { '>' };
+        let vertical_key = if row_delta < 0 { '^' } else { 'v' };
+        let horizontal = horizontal_key
+            .to_string()
+            .repeat(usize::from(column_delta.unsigned_abs()));
+        let vertical = vertical_key
+            .to_string()
+            .repeat(usize::from(row_delta.unsigned_abs()));
+
+        let mut paths = Vec::with_capacity(2);
+        let horizontal_corner = Position {
+            row: from.row,
+            column: to.column,
+        };
+        if horizontal_corner != self.gap {
+            paths.push(format!("{horizontal}{vertical}A"));
+        }
+        let vertical_corner = Position {
+            row: to.row,
+            column: from.column,
+        };
+        if vertical_corner != self.gap && (column_delta != 0 && row_delta != 0) {
+            paths.push(format!("{vertical}{horizontal}A"));
+        }
+        Ok(paths)
+    }
+}
+
+impl FromStr for Keypad {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buttons = FxHashMap::default();
+        let mut gap = None;
+        for (row, line) in s.lines().enumerate() {
+            for (column, c) in line.char_indices() {
+                let position = Position {
+                    row: i8::try_from(row)?,
+                    column: i8::try_from(column)?,
+                };
+                if c == ' ' {
+                    gap = Some(position);
+                } else {
+                    buttons.insert(c, position);
+                }
+            }
+        }
+        Ok(Self {
+            buttons,
+            gap: gap.context("Expected every keypad to have a gap")?,
+        })
+    }
+}
+
+/// A numeric keypad, operated by a chain of robots using directional keypads,
+/// the last of which is operated by a human.
+#[derive(Debug)]
+struct KeypadChain {
+    numeric: Keypad,
+    directional: Keypad,
+    robots: usize,
+    /// The number of human button presses needed for the robot at a given depth
+    /// to move its arm from one directional button to another and press it,
+    /// keyed by `(from, to, depth)`.
+    memo: FxHashMap<(char, char, usize), u64>,
+}
+
+impl KeypadChain {
+    fn new(robots: usize) -> anyhow::Result<Self> {
+        Ok(Self {
+            numeric: NUMERIC_KEYPAD.parse()?,
+            directional: DIRECTIONAL_KEYPAD.parse()?,
+            robots,
+            memo: FxHashMap::default(),
+        })
+    }
+
+    fn complexity(&mut self, code: &str) -> anyhow::Result<u64> {
+        let numeric_part: u64 = code
+            .trim_end_matches('A')
+            .parse()
+            .with_context(|| format!("Expected code {code:?} to be digits followed by `A`"))?;
+        Ok(self.presses(code)? * numeric_part)
+    }
+
+    /// The fewest button presses the human must make to type `code` on the numeric keypad.
+    fn presses(&mut self, code: &str) -> anyhow::Result<u64> {
+        let mut total = 0;
+        for (from, to) in button_pairs(code) {
+            let mut best = u64::MAX;
+            for path in self.numeric.paths(from, to)? {
+                best = best.min(self.directional_presses(&path, self.robots)?);
+            }
+            total += best;
+        }
+        Ok(total)
+    }
+
+    /// The fewest button presses the human must make for the directional keypad
+    /// `depth` robots away from them to have `sequence` typed on it.
+    fn directional_presses(&mut self, sequence: &str, depth: usize) -> anyhow::Result<u64> {
+        if depth == 0 {
+            return Ok(u64::try_from(sequence.len())?);
+        }
+        button_pairs(sequence)
+            .map(|(from, to)| self.press_cost(from, to, depth))
+            .sum()
+    }
+
+    fn press_cost(&mut self, from: char, to: char, depth: usize) -> anyhow::Result<u64> {
+        if let Some(cost) = self.memo.get(&(from, to, depth)) {
+            return Ok(*cost);
+        }
+        let mut best = u64::MAX;
+        for path in self.directional.paths(from, to)? {
+            best = best.min(self.directional_presses(&path, depth - 1)?);
+        }
+        self.memo.insert((from, to, depth), best);
+        Ok(best)
+    }
+
+    /// Expand `code` into an optimal sequence of buttons for the human to press.
+    ///
+    /// The output grows exponentially with the number of robots,
+    /// so this is only useful for checking shallow chains by hand.
+    fn expand(&mut self, code: &str) -> anyhow::Result<String> {
+        let mut sequence = String::new();
+        for (from, to) in button_pairs(code) {
+            let path = self.cheapest_path(&self.numeric.paths(from, to)?, self.robots)?;
+            sequence.push_str(&self.expand_directional(&path, self.robots)?);
+        }
+        Ok(sequence)
+    }
+
+    fn expand_directional(&mut self, sequence: &str, depth: usize) -> anyhow::Result<String> {
+        if depth == 0 {
+            return Ok(sequence.to_string());
+        }
+        let mut expanded = String::new();
+        for (from, to) in button_pairs(sequence) {
+            let path = self.cheapest_path(&self.directional.paths(from, to)?, depth - 1)?;
+            expanded.push_str(&self.expand_directional(&path, depth - 1)?);
+        }
+        Ok(expanded)
+    }
+
+    fn cheapest_path(&mut self, paths: &[String], depth: usize) -> anyhow::Result<String> {
+        let mut best: Option<(u64, &String)> = None;
+        for path in paths {
+            let cost = self.directional_presses(path, depth)?;
+            if best.is_none_or(|(best_cost, _)| cost < best_cost) {
+                best = Some((cost, path));
+            }
+        }
+        best.map(|(_, path)| path.clone())
+            .context("Expected at least one path between any two buttons")
+    }
+}
+
+/// Every robot arm starts pointing at `A`, and returns there after every press of `A`,
+/// so typing a sequence means moving from `A` to the first button, then from there to the next...
+fn button_pairs(sequence: &str) -> impl Iterator<Item = (char, char)> + '_ {
+    std::iter::once('A')
+        .chain(sequence.chars())
+        .zip(sequence.chars())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut chain = KeypadChain::new(ROBOTS).unwrap();
+        let codes = ["029A", "980A", "179A", "456A", "379A"];
+        let presses: Vec<u64> = codes
+            .iter()
+            .map(|code| chain.presses(code).unwrap())
+            .collect();
+        assert_eq!(presses, [68, 60, 68, 64, 64]);
+        let complexity: u64 = codes
+            .iter()
+            .map(|code| chain.complexity(code).unwrap())
+            .sum();
+        assert_eq!(complexity, 126_384);
+    }
+
+    #[test]
+    fn expand() {
+        assert_eq!(
+            KeypadChain::new(0).unwrap().expand("029A").unwrap().len(),
+            12
+        );
+        assert_eq!(
+            KeypadChain::new(1).unwrap().expand("029A").unwrap().len(),
+            28
+        );
+        let expanded = KeypadChain::new(ROBOTS).unwrap().expand("029A").unwrap();
+        assert_eq!(expanded.len(), 68);
+    }
+}
@@ -0,0 +1,36 @@
+use utilities::{parse, prng::SecretNumbers};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-22.txt"
+    )));
+
+    let initial_secrets: Vec<u64> = parse::lines(input).unwrap();
+    let timer = timer.parsed();
+
+    let answer: u64 = initial_secrets
+        .into_iter()
+        .map(|secret| SecretNumbers::new(secret).nth(SECRETS_PER_DAY - 1).unwrap())
+        .sum();
+    timer.solved();
+
+    println!("{answer}");
+}
+
+const SECRETS_PER_DAY: usize = 2000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let total: u64 = [1, 10, 100, 2024]
+            .into_iter()
+            .map(|secret| SecretNumbers::new(secret).nth(SECRETS_PER_DAY - 1).unwrap())
+            .sum();
+        assert_eq!(total, 37_327_623);
+    }
+}
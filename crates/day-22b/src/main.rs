@@ -0,0 +1,107 @@
+use std::fmt;
+
+use utilities::{parse, prng::SecretNumbers};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-22.txt"
+    )));
+
+    let initial_secrets: Vec<u64> = parse::lines(input).unwrap();
+    let timer = timer.parsed();
+    let (window, bananas) = best_change_window(&initial_secrets);
+    timer.solved();
+
+    println!("{bananas}");
+    // Only the total is the answer, so the sequence goes to stderr to keep stdout to just that.
+    eprintln!("Best sequence of price changes: {window}");
+}
+
+const SECRETS_PER_DAY: usize = 2000;
+
+/// The number of possible price changes: prices are single digits, so changes range from -9 to 9.
+const POSSIBLE_CHANGES: usize = 19;
+
+/// The number of distinct windows of four consecutive price changes.
+const POSSIBLE_WINDOWS: usize = POSSIBLE_CHANGES.pow(4);
+
+/// Four consecutive price changes, encoded as a base-19 number so it can be used as an array index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct ChangeWindow(usize);
+
+impl ChangeWindow {
+    /// Shift a new price change into the window, dropping the oldest one.
+    fn push(self, change: i8) -> Self {
+        let digit = usize::try_from(change + 9).expect("Expected price changes to be in -9..=9");
+        Self((self.0 * POSSIBLE_CHANGES + digit) % POSSIBLE_WINDOWS)
+    }
+
+    fn changes(self) -> [i8; 4] {
+        let mut changes = [0; 4];
+        let mut encoded = self.0;
+        for change in changes.iter_mut().rev() {
+            *change = i8::try_from(encoded % POSSIBLE_CHANGES).unwrap() - 9;
+            encoded /= POSSIBLE_CHANGES;
+        }
+        changes
+    }
+}
+
+impl fmt::Display for ChangeWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [first, second, third, fourth] = self.changes();
+        write!(f, "{first},{second},{third},{fourth}")
+    }
+}
+
+/// Find the window of four price changes that earns the most bananas in total across all buyers,
+/// returning the window and the number of bananas it earns.
+///
+/// Each buyer only sells the first time a window appears in their prices,
+/// so for every window we also track the last buyer that it was seen for.
+fn best_change_window(initial_secrets: &[u64]) -> (ChangeWindow, u32) {
+    let mut bananas = vec![0u32; POSSIBLE_WINDOWS];
+    let mut last_seen_for_buyer = vec![usize::MAX; POSSIBLE_WINDOWS];
+
+    for (buyer, initial_secret) in initial_secrets.iter().copied().enumerate() {
+        let prices = std::iter::once(initial_secret)
+            .chain(SecretNumbers::new(initial_secret).take(SECRETS_PER_DAY))
+            .map(|secret| i8::try_from(secret % 10).unwrap());
+
+        let mut window = ChangeWindow::default();
+        let mut previous_price = None;
+        for (i, price) in prices.enumerate() {
+            if let Some(previous_price) = previous_price {
+                window = window.push(price - previous_price);
+            }
+            previous_price = Some(price);
+            if i < 4 || last_seen_for_buyer[window.0] == buyer {
+                continue;
+            }
+            last_seen_for_buyer[window.0] = buyer;
+            bananas[window.0] += u32::from(price.unsigned_abs());
+        }
+    }
+
+    let (best_window, best_bananas) = bananas
+        .into_iter()
+        .enumerate()
+        .max_by_key(|(_, bananas)| *bananas)
+        .expect("Expected there to be at least one possible window");
+
+    (ChangeWindow(best_window), best_bananas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let (window, bananas) = best_change_window(&[1, 2, 3, 2024]);
+        assert_eq!(window.changes(), [-2, 1, -1, 3]);
+        assert_eq!(bananas, 23);
+    }
+}
@@ -0,0 +1,77 @@
+use utilities::{graph::UndirectedGraph, parse};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-23.txt"
+    )));
+
+    let network = parse_input(input).unwrap();
+    let timer = timer.parsed();
+    let answer = triangles_with_historian(&network);
+    timer.solved();
+    println!("{answer}");
+}
+
+fn parse_input(input: &str) -> anyhow::Result<UndirectedGraph> {
+    input.lines().map(|line| parse::pair(line, "-")).collect()
+}
+
+/// Count the sets of three inter-connected computers where at least one name starts with `t`.
+fn triangles_with_historian(network: &UndirectedGraph) -> usize {
+    network
+        .triangles()
+        .filter(|triangle| {
+            triangle
+                .iter()
+                .any(|computer| network.name(*computer).starts_with('t'))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test() {
+        let network = parse_input(INPUT).unwrap();
+        assert_eq!(network.triangles().count(), 12);
+        assert_eq!(triangles_with_historian(&network), 7);
+    }
+}
@@ -0,0 +1,76 @@
+use itertools::Itertools;
+use utilities::{graph::UndirectedGraph, parse};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-23.txt"
+    )));
+
+    let network = parse_input(input).unwrap();
+    let timer = timer.parsed();
+    let answer = lan_party_password(&network);
+    timer.solved();
+    println!("{answer}");
+}
+
+fn parse_input(input: &str) -> anyhow::Result<UndirectedGraph> {
+    input.lines().map(|line| parse::pair(line, "-")).collect()
+}
+
+/// The password is the names of the computers in the largest fully-connected set,
+/// sorted alphabetically and joined with commas.
+fn lan_party_password(network: &UndirectedGraph) -> String {
+    network
+        .maximum_clique()
+        .into_iter()
+        .map(|computer| network.name(computer))
+        .sorted_unstable()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test() {
+        let network = parse_input(INPUT).unwrap();
+        assert_eq!(lan_party_password(&network), "co,de,ka,ta");
+    }
+}
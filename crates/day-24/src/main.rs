@@ -0,0 +1,372 @@
+use std::{collections::VecDeque, fmt, str::FromStr};
+
+use anyhow::{anyhow, bail};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use utilities::parse;
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-24.txt"
+    )));
+
+    let circuit = Circuit::parse(input)?;
+    let timer = timer.parsed();
+
+    match std::env::args().nth(1).as_deref() {
+        None | Some("a") => {
+            let answer = circuit.evaluate()?;
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("b") => {
+            let answer = circuit.swapped_wires().join(",");
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("dot") => print!("{}", circuit.dot()),
+        Some(other) => bail!("Unknown command {other:?}: expected one of `a`, `b` or `dot`"),
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum GateKind {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateKind {
+    const fn apply(self, left: bool, right: bool) -> bool {
+        match self {
+            Self::And => left && right,
+            Self::Or => left || right,
+            Self::Xor => left ^ right,
+        }
+    }
+}
+
+impl fmt::Display for GateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::And => "AND",
+            Self::Or => "OR",
+            Self::Xor => "XOR",
+        })
+    }
+}
+
+impl FromStr for GateKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            "XOR" => Ok(Self::Xor),
+            _ => Err(anyhow!("Unknown gate kind {s:?}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Gate<'a> {
+    kind: GateKind,
+    inputs: [&'a str; 2],
+    output: &'a str,
+}
+
+impl<'a> Gate<'a> {
+    fn parse(line: &'a str) -> anyhow::Result<Self> {
+        let (expression, output) = parse::pair(line, " -> ")?;
+        let Some((left, kind, right)) = expression.split(' ').collect_tuple() else {
+            bail!("Expected a gate expression like `x00 AND y00`, got {expression:?}");
+        };
+        Ok(Self {
+            kind: kind.parse()?,
+            inputs: [left, right],
+            output,
+        })
+    }
+
+    fn has_input_wires(&self) -> bool {
+        self.inputs.iter().all(|wire| is_input_wire(wire))
+    }
+
+    fn has_first_bit_inputs(&self) -> bool {
+        self.inputs.iter().all(|wire| wire.ends_with("00"))
+    }
+}
+
+fn is_input_wire(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+#[derive(Debug)]
+struct Circuit<'a> {
+    initial_values: FxHashMap<&'a str, bool>,
+    gates: Vec<Gate<'a>>,
+}
+
+impl<'a> Circuit<'a> {
+    fn parse(input: &'a str) -> anyhow::Result<Self> {
+        let [wires_input, gates_input] = parse::sections(input)?;
+
+        let initial_values = wires_input
+            .lines()
+            .map(|line| {
+                let (wire, value) = parse::pair(line, ": ")?;
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => bail!("Expected a wire's initial value to be 0 or 1, got {value:?}"),
+                };
+                Ok((wire, value))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let gates = gates_input
+            .lines()
+            .map(Gate::parse)
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            initial_values,
+            gates,
+        })
+    }
+
+    /// Evaluate every gate in topological order, returning the value on every wire.
+    fn wire_values(&self) -> anyhow::Result<FxHashMap<&'a str, bool>> {
+        let mut dependents: FxHashMap<&str, Vec<usize>> = FxHashMap::default();
+        for (index, gate) in self.gates.iter().enumerate() {
+            for input in gate.inputs {
+                dependents.entry(input).or_default().push(index);
+            }
+        }
+
+        let mut values = self.initial_values.clone();
+        let mut ready: VecDeque<&str> = values.keys().copied().collect();
+        let mut unresolved_inputs: Vec<u8> = vec![2; self.gates.len()];
+
+        while let Some(wire) = ready.pop_front() {
+            for &index in dependents.get(wire).into_iter().flatten() {
+                unresolved_inputs[index] -= 1;
+                if unresolved_inputs[index] > 0 {
+                    continue;
+                }
+                let Gate {
+                    kind,
+                    inputs: [left, right],
+                    output,
+                } = self.gates[index];
+                values.insert(output, kind.apply(values[left], values[right]));
+                ready.push_back(output);
+            }
+        }
+
+        if let Some(gate) = self
+            .gates
+            .iter()
+            .find(|gate| !values.contains_key(gate.output))
+        {
+            bail!(
+                "Could not evaluate the gate outputting to {}: is there a cycle or an unset wire?",
+                gate.output
+            );
+        }
+
+        Ok(values)
+    }
+
+    /// Assemble the bits on the `z` wires into a number, with `z00` as the least significant bit.
+    fn evaluate(&self) -> anyhow::Result<u64> {
+        let mut result = 0;
+        for (wire, value) in self.wire_values()? {
+            let Some(bit) = wire.strip_prefix('z') else {
+                continue;
+            };
+            let bit: u32 = bit.parse()?;
+            if bit >= u64::BITS {
+                bail!("Output wire {wire} does not fit into a 64-bit number");
+            }
+            result |= u64::from(value) << bit;
+        }
+        Ok(result)
+    }
+
+    /// Find the output wires that must have been swapped for the circuit to be a ripple-carry adder.
+    ///
+    /// Every bit after the first should be wired up as a full adder:
+    ///
+    /// ```text
+    /// x XOR y -> sum          x AND y -> carry_a
+    /// sum XOR carry_in -> z   sum AND carry_in -> carry_b
+    /// carry_a OR carry_b -> carry_out
+    /// ```
+    ///
+    /// Rather than trying to work out which gate is which,
+    /// we flag every gate whose output breaks one of the structural rules that follow from this.
+    fn swapped_wires(&self) -> Vec<&'a str> {
+        let last_output = self
+            .gates
+            .iter()
+            .map(|gate| gate.output)
+            .filter(|wire| wire.starts_with('z'))
+            .max()
+            .unwrap_or_default();
+
+        let mut consumers: FxHashMap<&str, FxHashSet<GateKind>> = FxHashMap::default();
+        for gate in &self.gates {
+            for input in gate.inputs {
+                consumers.entry(input).or_default().insert(gate.kind);
+            }
+        }
+        let feeds_into = |wire: &str, kind: GateKind| {
+            consumers
+                .get(wire)
+                .is_some_and(|kinds| kinds.contains(&kind))
+        };
+        let only_feeds_into = |wire: &str, kind: GateKind| {
+            consumers
+                .get(wire)
+                .is_some_and(|kinds| kinds.iter().all(|consumer| *consumer == kind))
+        };
+
+        self.gates
+            .iter()
+            .filter(|gate| {
+                let output = gate.output;
+                match gate.kind {
+                    // Every output bit except the final carry comes straight out of an XOR gate...
+                    _ if output == last_output => gate.kind != GateKind::Or,
+                    _ if output.starts_with('z') => gate.kind != GateKind::Xor,
+                    // ...and the only other XOR gates are the ones that add the two input bits,
+                    // whose result must then be XORed with the carry (except for the first bit).
+                    GateKind::Xor => {
+                        !gate.has_input_wires()
+                            || (!gate.has_first_bit_inputs() && !feeds_into(output, GateKind::Xor))
+                    }
+                    // Both of the carry candidates for a bit are ORed together
+                    // (except for the first bit, which has no carry in).
+                    GateKind::And => {
+                        !gate.has_first_bit_inputs() && !only_feeds_into(output, GateKind::Or)
+                    }
+                    GateKind::Or => false,
+                }
+            })
+            .map(|gate| gate.output)
+            .sorted_unstable()
+            .collect()
+    }
+
+    fn dot(&self) -> Dot<'_, 'a> {
+        Dot(self)
+    }
+}
+
+/// Graphviz rendering of a [`Circuit`], with one node per wire and one node per gate.
+struct Dot<'c, 'a>(&'c Circuit<'a>);
+
+impl fmt::Display for Dot<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Dot(circuit) = self;
+        writeln!(f, "digraph circuit {{")?;
+        writeln!(f, "    rankdir=LR;")?;
+        for wire in circuit.initial_values.keys().sorted_unstable() {
+            writeln!(f, "    {wire} [shape=box];")?;
+        }
+        for (index, gate) in circuit.gates.iter().enumerate() {
+            let Gate {
+                kind,
+                inputs: [left, right],
+                output,
+            } = gate;
+            let shape = if output.starts_with('z') {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            writeln!(f, "    gate{index} [label=\"{kind}\", shape=diamond];")?;
+            writeln!(f, "    {output} [shape={shape}];")?;
+            writeln!(f, "    {left} -> gate{index};")?;
+            writeln!(f, "    {right} -> gate{index};")?;
+            writeln!(f, "    gate{index} -> {output};")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn part_a() {
+        let input = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+        assert_eq!(Circuit::parse(input).unwrap().evaluate().unwrap(), 4);
+    }
+
+    /// Build a correctly wired ripple-carry adder for two `bits`-bit numbers.
+    fn adder(bits: u32, x: u64, y: u64) -> String {
+        let mut input = String::new();
+        for (name, value) in [('x', x), ('y', y)] {
+            for bit in 0..bits {
+                writeln!(input, "{name}{bit:02}: {}", (value >> bit) & 1).unwrap();
+            }
+        }
+        input.push('\n');
+        input.push_str("x00 XOR y00 -> z00\nx00 AND y00 -> c00\n");
+        for bit in 1..bits {
+            let carry_in = format!("c{:02}", bit - 1);
+            let carry_out = if bit == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{bit:02}")
+            };
+            writeln!(input, "x{bit:02} XOR y{bit:02} -> s{bit:02}").unwrap();
+            writeln!(input, "x{bit:02} AND y{bit:02} -> a{bit:02}").unwrap();
+            writeln!(input, "s{bit:02} XOR {carry_in} -> z{bit:02}").unwrap();
+            writeln!(input, "s{bit:02} AND {carry_in} -> b{bit:02}").unwrap();
+            writeln!(input, "a{bit:02} OR b{bit:02} -> {carry_out}").unwrap();
+        }
+        input
+    }
+
+    #[test]
+    fn correct_adder() {
+        let input = adder(6, 45, 29);
+        let circuit = Circuit::parse(&input).unwrap();
+        assert_eq!(circuit.evaluate().unwrap(), 74);
+        assert!(circuit.swapped_wires().is_empty());
+    }
+
+    #[test]
+    fn swapped_adder() {
+        let input = adder(6, 45, 29)
+            .replace("-> s02", "-> tmp")
+            .replace("-> a02", "-> s02")
+            .replace("-> tmp", "-> a02")
+            .replace("-> z01", "-> tmp")
+            .replace("-> b01", "-> z01")
+            .replace("-> tmp", "-> b01");
+        let circuit = Circuit::parse(&input).unwrap();
+        assert_eq!(circuit.swapped_wires(), ["a02", "b01", "s02", "z01"]);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::bail;
+use itertools::Itertools;
+use utilities::parse;
+
+fn main() -> anyhow::Result<()> {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-25.txt"
+    )));
+
+    let (locks, keys) = parse_input(input)?;
+    let timer = timer.parsed();
+
+    match std::env::args().nth(1).as_deref() {
+        None => {
+            let answer = fitting_pairs(&locks, &keys).count();
+            timer.solved();
+            println!("{answer}");
+        }
+        Some("pairs") => {
+            for (lock_index, key_index) in fitting_pairs(&locks, &keys) {
+                println!(
+                    "Lock {lock_index} ({}) fits key {key_index} ({})",
+                    locks[lock_index], keys[key_index]
+                );
+            }
+        }
+        Some(other) => bail!("Unknown command {other:?}: expected `pairs` or no arguments"),
+    }
+
+    Ok(())
+}
+
+const COLUMNS: usize = 5;
+const ROWS: usize = 7;
+
+/// The space available in each column once the top and bottom rows are taken into account.
+const AVAILABLE_SPACE: u8 = 5;
+
+fn parse_input(input: &str) -> anyhow::Result<(Vec<Heights>, Vec<Heights>)> {
+    let mut locks = vec![];
+    let mut keys = vec![];
+
+    for block in parse::blocks(input) {
+        match block.parse()? {
+            Schematic::Lock(heights) => locks.push(heights),
+            Schematic::Key(heights) => keys.push(heights),
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+/// Iterate over the `(lock, key)` index pairs where the key fits the lock without overlapping.
+fn fitting_pairs<'a>(
+    locks: &'a [Heights],
+    keys: &'a [Heights],
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    locks
+        .iter()
+        .enumerate()
+        .cartesian_product(keys.iter().enumerate())
+        .filter(|((_, lock), (_, key))| lock.fits(**key))
+        .map(|((lock_index, _), (key_index, _))| (lock_index, key_index))
+}
+
+/// The height of each column of a lock's pins or a key's cuts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Heights([u8; COLUMNS]);
+
+impl Heights {
+    fn fits(self, other: Heights) -> bool {
+        self.0
+            .iter()
+            .zip(other.0)
+            .all(|(a, b)| a + b <= AVAILABLE_SPACE)
+    }
+}
+
+impl fmt::Display for Heights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.iter().join(","))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Schematic {
+    Lock(Heights),
+    Key(Heights),
+}
+
+impl FromStr for Schematic {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = s.lines().collect();
+        if rows.len() != ROWS || rows.iter().any(|row| row.len() != COLUMNS) {
+            bail!("Expected every schematic to be {COLUMNS} columns wide and {ROWS} rows tall");
+        }
+
+        let mut heights = [0; COLUMNS];
+        for row in &rows[1..ROWS - 1] {
+            for (column, c) in row.char_indices() {
+                match c {
+                    '#' => heights[column] += 1,
+                    '.' => {}
+                    _ => bail!("Unexpected character '{c}'!"),
+                }
+            }
+        }
+
+        match rows[0] {
+            "#####" => Ok(Self::Lock(Heights(heights))),
+            "....." => Ok(Self::Key(Heights(heights))),
+            _ => bail!("Expected the top row to be either all `#` (a lock) or all `.` (a key)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn test() {
+        let (locks, keys) = parse_input(INPUT).unwrap();
+        assert_eq!(locks[0], Heights([0, 5, 3, 4, 3]));
+        assert_eq!(keys[0], Heights([5, 0, 2, 1, 3]));
+        assert_eq!(
+            fitting_pairs(&locks, &keys).collect::<Vec<_>>(),
+            [(0, 2), (1, 1), (1, 2)]
+        );
+    }
+}
@@ -2,16 +2,24 @@ use itertools::Itertools;
 use std::{num::ParseIntError, str::FromStr};
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-2.txt"
-    ));
+    )));
 
-    let answer = input
+    let reports: Vec<Report> = input
         .lines()
-        .map(|line| Report::from_str(line).unwrap().safety())
+        .map(|line| Report::from_str(line).unwrap())
+        .collect();
+    let timer = timer.parsed();
+
+    let answer = reports
+        .iter()
+        .map(Report::safety)
         .filter(|safety| safety.is_safe())
         .count();
+    timer.solved();
 
     println!("{answer}");
 }
@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-3.txt"
-    ));
+    )));
+    // The memory is scanned as it is, so there is nothing more to parse.
+    let timer = timer.parsed();
 
     let mul_regex = regex::Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
 
@@ -13,6 +16,7 @@ fn main() {
         let (_, [first, second]) = caps.extract();
         result += u32::from_str(first).unwrap() * u32::from_str(second).unwrap();
     }
+    timer.solved();
 
     println!("{result}");
 }
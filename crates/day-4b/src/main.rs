@@ -1,62 +1,38 @@
 use anyhow::anyhow;
 use std::str::FromStr;
 
-use utilities::maps::{EightPointCompass, Grid, Point};
+use utilities::{maps::Grid, pattern::Pattern};
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-4.txt"
-    ));
+    )));
 
     let wordsearch: Wordsearch = input.parse().unwrap();
+    let timer = timer.parsed();
     let answer = wordsearch.total_christmases();
+    timer.solved();
     println!("{answer}");
 }
 
 const MAX_COORDINATE: u16 = 140;
-type WordsearchPoint = Point<MAX_COORDINATE>;
+
+/// Two `MAS`es crossing in the shape of an X. Every other way of writing it
+/// is a rotation or reflection of this one.
+const X_MAS: &str = "\
+M?S
+?A?
+M?S";
 
 #[derive(Debug)]
 struct Wordsearch(Grid<MAX_COORDINATE, Letter>);
 
 impl Wordsearch {
     fn total_christmases(&self) -> usize {
-        self.0
-            .iter()
-            .filter(|(point, letter)| self.contains_x_mas(**point, **letter))
-            .count()
-    }
-
-    fn letter_at(&self, point: WordsearchPoint) -> Letter {
-        self.0[&point]
-    }
-
-    fn contains_x_mas(&self, point: WordsearchPoint, letter: Letter) -> bool {
-        if !letter.is_a() {
-            return false;
-        }
-
-        let Some(north_west) = point.shift(EightPointCompass::NorthWest) else {
-            return false;
-        };
-        let Some(south_east) = point.shift(EightPointCompass::SouthEast) else {
-            return false;
-        };
-        let Some(north_east) = point.shift(EightPointCompass::NorthEast) else {
-            return false;
-        };
-        let Some(south_west) = point.shift(EightPointCompass::SouthWest) else {
-            return false;
-        };
-
-        matches!(
-            (self.letter_at(north_west), self.letter_at(south_east)),
-            (Letter::M, Letter::S) | (Letter::S, Letter::M)
-        ) && matches!(
-            (self.letter_at(north_east), self.letter_at(south_west)),
-            (Letter::M, Letter::S) | (Letter::S, Letter::M)
-        )
+        let x_mas: Pattern<Letter> = X_MAS.parse().unwrap();
+        x_mas.find_in_all_orientations(&self.0).count()
     }
 }
 
@@ -76,12 +52,6 @@ enum Letter {
     S,
 }
 
-impl Letter {
-    fn is_a(self) -> bool {
-        matches!(self, Letter::A)
-    }
-}
-
 impl TryFrom<char> for Letter {
     type Error = anyhow::Error;
 
@@ -95,3 +65,26 @@ impl TryFrom<char> for Letter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn test() {
+        let wordsearch: Wordsearch = INPUT.parse().unwrap();
+        assert_eq!(wordsearch.total_christmases(), 9);
+    }
+}
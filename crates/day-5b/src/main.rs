@@ -1,16 +1,18 @@
 use std::{collections::HashSet, num::ParseIntError, str::FromStr};
 
-use anyhow::{bail, Context};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
+use utilities::parse;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-5.txt"
-    ));
+    )));
 
     let (rule_table, updates) = parse_input(input).unwrap();
+    let timer = timer.parsed();
 
     let mut bad_updates: Vec<(Update, HashSet<Rule>)> = updates
         .into_iter()
@@ -26,13 +28,10 @@ fn main() {
 
     for (update, rules) in &mut bad_updates {
         'outer: loop {
-            'inner: for rule in rules.iter().copied() {
-                match update.satisfies_rule(rule) {
-                    Ok(()) => continue 'inner,
-                    Err((first, second)) => {
-                        update.swap_indices(first, second);
-                        continue 'outer;
-                    }
+            for rule in rules.iter().copied() {
+                if let Err((first, second)) = update.satisfies_rule(rule) {
+                    update.swap_indices(first, second);
+                    continue 'outer;
                 }
             }
             break 'outer;
@@ -43,25 +42,15 @@ fn main() {
         .into_iter()
         .map(|(update, _)| u16::from(update.middle_page()))
         .sum();
+    timer.solved();
 
     println!("{answer}");
 }
 
 fn parse_input(input: &str) -> anyhow::Result<(RuleTable, Vec<Update>)> {
-    let (rules_input, updates_input) = input.split_once("\n\n").context(
-        "Expected a double line break in between the rules and the updates list in the input",
-    )?;
-
-    if updates_input.contains("\n\n") {
-        bail!("Expected `\n\n` to appear only once in the input!");
-    }
-
+    let [rules_input, updates_input] = parse::sections(input)?;
     let rule_table = rules_input.parse()?;
-
-    let updates = updates_input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<_, _>>()?;
+    let updates = parse::lines(updates_input)?;
 
     Ok((rule_table, updates))
 }
@@ -76,17 +65,11 @@ impl FromStr for Rule {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (earlier, later) = s
-            .split_once('|')
-            .context("Expected a pipe character in a rule definition")?;
-
-        if later.contains('|') {
-            bail!("Expected only one `|` character in the input");
-        }
+        let (earlier, later) = parse::pair(s, "|")?;
 
         Ok(Self {
-            earlier: earlier.parse()?,
-            later: later.parse()?,
+            earlier: parse::item(earlier)?,
+            later: parse::item(later)?,
         })
     }
 }
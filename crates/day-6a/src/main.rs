@@ -2,37 +2,59 @@ use std::str::FromStr;
 
 use anyhow::{bail, Context};
 use rustc_hash::FxHashSet;
-use utilities::maps::{FourPointCompass, Point, Grid};
+use utilities::maps::{FourPointCompass, Grid, Point};
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-6.txt"
-    ));
+    )));
 
     let PuzzleInput {
         map,
         start_location,
     } = input.parse().unwrap();
+    let timer = timer.parsed();
 
-    let mut current_location = start_location;
-    let mut points_encountered = FxHashSet::from_iter([start_location]);
-    let mut current_direction = FourPointCompass::North;
+    let answer = points_encountered(&map, start_location).len();
+    timer.solved();
+    println!("{answer}");
+}
 
-    loop {
-        debug_assert!(!map[&current_location].is_obstructed());
-        let Some(candidate) = current_location.shift(current_direction) else {
-            break;
-        };
-        if map[&candidate].is_obstructed() {
-            current_direction.shift_90_degrees();
+/// Where the guard is and which way they're facing.
+///
+/// The guard's walk is completely determined by this state,
+/// so it can also be fed to `utilities::cycle` to find out whether the guard walks in a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Guard {
+    location: LabPoint,
+    direction: FourPointCompass,
+}
+
+impl Guard {
+    /// The guard's state after one more step, or `None` if they leave the map.
+    fn step(self, map: &LabMap) -> Option<Guard> {
+        debug_assert!(!map[&self.location].is_obstructed());
+        let candidate = self.location.shift(self.direction)?;
+        let mut next = self;
+        if map.get(&candidate)?.is_obstructed() {
+            next.direction.shift_90_degrees();
         } else {
-            points_encountered.insert(candidate);
-            current_location = candidate;
+            next.location = candidate;
         }
+        Some(next)
     }
+}
 
-    println!("{}", points_encountered.len());
+fn points_encountered(map: &LabMap, start_location: LabPoint) -> FxHashSet<LabPoint> {
+    let guard = Guard {
+        location: start_location,
+        direction: FourPointCompass::North,
+    };
+    std::iter::successors(Some(guard), |guard| guard.step(map))
+        .map(|guard| guard.location)
+        .collect()
 }
 
 #[derive(Debug)]
@@ -86,3 +108,53 @@ impl PointContents {
         matches!(self, Self::Obstructed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use utilities::cycle;
+
+    use super::*;
+
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test() {
+        let PuzzleInput {
+            map,
+            start_location,
+        } = INPUT.parse().unwrap();
+        assert_eq!(points_encountered(&map, start_location).len(), 41);
+    }
+
+    #[test]
+    fn loop_detection() {
+        let PuzzleInput {
+            mut map,
+            start_location,
+        } = INPUT.parse().unwrap();
+        let guard = Guard {
+            location: start_location,
+            direction: FourPointCompass::North,
+        };
+        assert_eq!(
+            cycle::find_with_hashing(guard, |guard| guard.step(&map)),
+            None
+        );
+
+        map.insert(
+            LabPoint::try_from((4, 7)).unwrap(),
+            PointContents::Obstructed,
+        );
+        assert!(cycle::find_with_hashing(guard, |guard| guard.step(&map)).is_some());
+    }
+}
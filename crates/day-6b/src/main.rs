@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+use rustc_hash::FxHashSet;
+use utilities::{
+    cycle,
+    maps::{FourPointCompass, Grid, Point},
+    parallel,
+};
+
+fn main() {
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
+        std::env!("CARGO_MANIFEST_DIR"),
+        "/../../inputs/day-6.txt"
+    )));
+
+    let PuzzleInput {
+        map,
+        start_location,
+    } = input.parse().unwrap();
+    let timer = timer.parsed();
+
+    let answer = loop_causing_obstructions(&map, start_location);
+    timer.solved();
+    println!("{answer}");
+}
+
+/// Where the guard is and which way they're facing.
+///
+/// The guard's walk is completely determined by this state,
+/// so it can be fed to `utilities::cycle` to find out whether the guard walks in a loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Guard {
+    location: LabPoint,
+    direction: FourPointCompass,
+}
+
+impl Guard {
+    fn new(location: LabPoint) -> Self {
+        Self {
+            location,
+            direction: FourPointCompass::North,
+        }
+    }
+
+    /// The guard's state after one more step, or `None` if they leave the map.
+    ///
+    /// `extra_obstruction` is treated as obstructed too, so that each candidate position
+    /// for a new obstruction can be tried without copying the map.
+    fn step(self, map: &LabMap, extra_obstruction: Option<LabPoint>) -> Option<Guard> {
+        let candidate = self.location.shift(self.direction)?;
+        let mut next = self;
+        if map.get(&candidate)?.is_obstructed() || Some(candidate) == extra_obstruction {
+            next.direction.shift_90_degrees();
+        } else {
+            next.location = candidate;
+        }
+        Some(next)
+    }
+}
+
+fn points_encountered(map: &LabMap, start_location: LabPoint) -> FxHashSet<LabPoint> {
+    std::iter::successors(Some(Guard::new(start_location)), |guard| {
+        guard.step(map, None)
+    })
+    .map(|guard| guard.location)
+    .collect()
+}
+
+/// Count the positions where a single new obstruction would trap the guard in a loop.
+///
+/// An obstruction off the guard's original path can never be reached, so only the points on
+/// that path (apart from where the guard starts) are worth trying. Each try is independent
+/// of the others, so they are spread across the shared thread pool.
+fn loop_causing_obstructions(map: &LabMap, start_location: LabPoint) -> usize {
+    let candidates: Vec<LabPoint> = points_encountered(map, start_location)
+        .into_iter()
+        .filter(|point| *point != start_location)
+        .collect();
+    parallel::count(&candidates, |&obstruction| {
+        cycle::brent(Guard::new(start_location), |guard| {
+            guard.step(map, Some(obstruction))
+        })
+        .is_some()
+    })
+}
+
+#[derive(Debug)]
+struct PuzzleInput {
+    map: LabMap,
+    start_location: LabPoint,
+}
+
+impl FromStr for PuzzleInput {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = LabMap::default();
+        let mut start_location = None;
+        for (zero_based_y, line) in s.lines().enumerate() {
+            for (zero_based_x, c) in line.char_indices() {
+                let point = LabPoint::try_from((zero_based_x + 1, zero_based_y + 1))?;
+                let contents = match c {
+                    '.' => PointContents::Empty,
+                    '#' => PointContents::Obstructed,
+                    '^' => {
+                        start_location = Some(point);
+                        PointContents::Empty
+                    }
+                    _ => bail!("Unexpected character '{c}'!"),
+                };
+                map.insert(point, contents);
+            }
+        }
+        let start_location =
+            start_location.context("Expected to find '^' somewhere in the map!")?;
+        Ok(Self {
+            map,
+            start_location,
+        })
+    }
+}
+
+const MAX_COORDINATE: u16 = 130;
+type LabPoint = Point<MAX_COORDINATE>;
+type LabMap = Grid<MAX_COORDINATE, PointContents>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PointContents {
+    Obstructed,
+    Empty,
+}
+
+impl PointContents {
+    const fn is_obstructed(self) -> bool {
+        matches!(self, Self::Obstructed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test() {
+        let PuzzleInput {
+            map,
+            start_location,
+        } = INPUT.parse().unwrap();
+        assert_eq!(loop_causing_obstructions(&map, start_location), 6);
+    }
+}
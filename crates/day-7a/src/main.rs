@@ -1,20 +1,27 @@
 use std::str::FromStr;
 
-use anyhow::{bail, Context};
 use itertools::Itertools;
+use utilities::parse;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-7.txt"
-    ));
+    )));
 
-    let answer: u64 = input
+    let equations: Vec<Equation> = input
         .lines()
         .map(|line| Equation::from_str(line).unwrap())
-        .filter(Equation::could_be_true)
+        .collect();
+    let timer = timer.parsed();
+
+    let answer: u64 = equations
+        .iter()
+        .filter(|equation| equation.could_be_true())
         .map(|equation| equation.test_value)
         .sum();
+    timer.solved();
 
     println!("{answer}");
 }
@@ -51,20 +58,11 @@ impl FromStr for Equation {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s
-            .split_once(": ")
-            .context("Expected substring ': ' to appear on each line")?;
-
-        if right.contains(':') {
-            bail!("Expected only one ':' character per line");
-        }
-
-        let test_value = left.parse()?;
-        let remaining_numbers = right.split(' ').map(str::parse).collect::<Result<_, _>>()?;
+        let (test_value, remaining_numbers) = parse::key_values(s)?;
 
         Ok(Self {
             test_value,
-            remaining_numbers,
+            remaining_numbers: remaining_numbers.into_boxed_slice(),
         })
     }
 }
@@ -4,15 +4,17 @@ use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use smallvec::SmallVec;
-use utilities::maps::{self, Delta};
+use utilities::maps;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-8.txt"
-    ));
+    )));
 
     let locations = AntennaLocations::from_str(input).unwrap();
+    let timer = timer.parsed();
 
     let mut antinodes: FxHashSet<Point> = FxHashSet::default();
     for (_frequency, frequency_locations) in &locations {
@@ -20,6 +22,7 @@ fn main() {
             antinodes.extend(find_antinode_locations(pair[0], pair[1]));
         }
     }
+    timer.solved();
 
     println!("{}", antinodes.len());
 }
@@ -66,7 +69,7 @@ impl FromStr for AntennaLocations {
 ///
 /// There will be exactly 0, 1 or 2 antinode locations formed by any two antennae.
 fn find_antinode_locations(point_a: Point, point_b: Point) -> SmallVec<[Point; 2]> {
-    let delta = Delta::try_from((point_a, point_b)).unwrap();
+    let delta = point_a - point_b;
     let mut locations = SmallVec::default();
     if let Some(point) = point_a.apply_delta(delta) {
         locations.push(point);
@@ -3,15 +3,17 @@ use std::str::FromStr;
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use utilities::maps::{self, Delta};
+use utilities::maps;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-8.txt"
-    ));
+    )));
 
     let locations = AntennaLocations::from_str(input).unwrap();
+    let timer = timer.parsed();
 
     let mut antinodes: FxHashSet<Point> = FxHashSet::default();
     for (_frequency, frequency_locations) in &locations {
@@ -19,6 +21,7 @@ fn main() {
             antinodes.extend(find_antinode_locations(pair[0], pair[1]));
         }
     }
+    timer.solved();
 
     println!("{}", antinodes.len());
 }
@@ -62,26 +65,20 @@ impl FromStr for AntennaLocations {
 
 /// Given two locations on the grid that are known to contain antennae with the same frequency,
 /// return a list of antinode locations formed by these two antennae.
-fn find_antinode_locations(mut point_a: Point, mut point_b: Point) -> Vec<Point> {
-    let delta = Delta::try_from((point_a, point_b)).unwrap();
-    let mut locations = vec![point_a, point_b];
-
-    loop {
-        let Some(point) = point_a.apply_delta(delta) else {
-            break;
-        };
-        point_a = point;
-        locations.push(point);
-    }
-
-    let reversed_delta = delta.reversed();
-
-    loop {
-        let Some(point) = point_b.apply_delta(reversed_delta) else {
-            break;
-        };
-        point_b = point;
-        locations.push(point);
+///
+/// Every grid point on the line through the two antennae is an antinode. We step along the line
+/// by the primitive delta between the antennae, so that we don't skip over any grid points
+/// when the two components of the delta share a common factor.
+fn find_antinode_locations(point_a: Point, point_b: Point) -> Vec<Point> {
+    let step = (point_a - point_b).primitive();
+    let mut locations = vec![point_a];
+
+    for step in [step, step.reversed()] {
+        let mut point = point_a;
+        while let Some(next_point) = point.apply_delta(step) {
+            point = next_point;
+            locations.push(point);
+        }
     }
 
     locations
@@ -91,3 +88,17 @@ fn find_antinode_locations(mut point_a: Point, mut point_b: Point) -> Vec<Point>
 struct Antenna {
     frequency: char,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn antinodes_between_antennae() {
+        let point = |x, y| Point::try_from((x, y)).unwrap();
+        let antinodes = find_antinode_locations(point(1, 1), point(3, 5));
+        assert!(antinodes.contains(&point(2, 3)));
+        assert!(antinodes.contains(&point(4, 7)));
+        assert_eq!(antinodes.len(), 25);
+    }
+}
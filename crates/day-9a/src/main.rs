@@ -1,10 +1,11 @@
 use std::collections::VecDeque;
 
 fn main() {
-    let input = include_str!(concat!(
+    let timer = utilities::timing::start();
+    let input = utilities::input::with_override(include_str!(concat!(
         std::env!("CARGO_MANIFEST_DIR"),
         "/../../inputs/day-9.txt"
-    ));
+    )));
 
     let mut disk: VecDeque<Option<usize>> = VecDeque::with_capacity(20000);
     for (i, c) in input.char_indices() {
@@ -22,21 +23,18 @@ fn main() {
         }
     }
 
+    let timer = timer.parsed();
+
     let mut new_disk: Vec<usize> = Vec::with_capacity(10000);
 
     while let Some(item) = disk.pop_front() {
         match item {
             Some(item) => new_disk.push(item),
-            None => loop {
-                match disk.pop_back() {
-                    Some(None) => continue,
-                    Some(Some(item)) => {
-                        new_disk.push(item);
-                        break;
-                    }
-                    None => break,
+            None => {
+                if let Some(item) = std::iter::from_fn(|| disk.pop_back()).flatten().next() {
+                    new_disk.push(item);
                 }
-            },
+            }
         }
     }
 
@@ -45,6 +43,7 @@ fn main() {
         .enumerate()
         .map(|(index, file_id)| index * file_id)
         .sum();
+    timer.solved();
 
     println!("{answer}");
 }
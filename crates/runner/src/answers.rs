@@ -0,0 +1,221 @@
+//! The answer store: a TOML file of known-good answers for each input, day and part.
+//!
+//! ```toml
+//! [default.day-6]
+//! a = "41"
+//! b = "6"
+//!
+//! [alice.day-6]
+//! a = "5208"
+//! ```
+//!
+//! The table names are input names (see [`input_path`](crate::solver::input_path)), so answers for inputs
+//! from several people's accounts can live side by side.
+
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+use anyhow::{bail, Context};
+
+use crate::solver::{Part, Solver};
+
+/// The name of the store, in the root of the workspace.
+pub(crate) const FILE_NAME: &str = "answers.toml";
+
+/// The expected answers, keyed by input name, day and part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Answers(BTreeMap<(String, u8, Part), String>);
+
+impl Answers {
+    /// Load the store, which is empty if the file doesn't exist yet.
+    pub(crate) fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_toml())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> anyhow::Result<Self> {
+        let mut answers = Self::default();
+        let table: toml::Table = contents.parse()?;
+        for (input, days) in table {
+            validate_input_name(&input)?;
+            let toml::Value::Table(days) = days else {
+                bail!("Expected `{input}` to be a table of days");
+            };
+            for (key, parts) in days {
+                let day: u8 = key
+                    .strip_prefix("day-")
+                    .and_then(|day| day.parse().ok())
+                    .with_context(|| {
+                        format!("Expected `{input}.{key}` to be a day such as `day-6`")
+                    })?;
+                let toml::Value::Table(parts) = parts else {
+                    bail!("Expected `{input}.{key}` to be a table of parts");
+                };
+                for (part, answer) in parts {
+                    let part = match part.as_str() {
+                        "a" => Part::A,
+                        "b" => Part::B,
+                        _ => bail!("Unknown part `{input}.{key}.{part}`: expected `a` or `b`"),
+                    };
+                    let toml::Value::String(answer) = answer else {
+                        bail!("Expected `{input}.{key}.{part}` to be a string");
+                    };
+                    answers.0.insert((input.clone(), day, part), answer);
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Write the store out with its tables in order, so that diffs of it stay small.
+    fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut current = None;
+        for ((input, day, part), answer) in &self.0 {
+            if current != Some((input, day)) {
+                if current.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[{input}.day-{day}]").unwrap();
+                current = Some((input, day));
+            }
+            writeln!(toml, "{part} = {}", toml::Value::String(answer.clone())).unwrap();
+        }
+        toml
+    }
+
+    pub(crate) fn expected(&self, input: &str, solver: &Solver) -> Option<&str> {
+        self.0
+            .get(&(input.to_owned(), solver.day, solver.part))
+            .map(String::as_str)
+    }
+
+    /// Record an answer, returning the answer it replaced, if any.
+    pub(crate) fn record(
+        &mut self,
+        input: &str,
+        solver: &Solver,
+        answer: String,
+    ) -> Option<String> {
+        self.0
+            .insert((input.to_owned(), solver.day, solver.part), answer)
+    }
+
+    /// Every input, day and part with a known answer.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, u8, Part, &str)> {
+        self.0
+            .iter()
+            .map(|((input, day, part), answer)| (input.as_str(), *day, *part, answer.as_str()))
+    }
+}
+
+/// Input names become TOML keys and directory names, so keep them simple.
+pub(crate) fn validate_input_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        bail!("Invalid input name {name:?}: use only letters, digits, `-` and `_`");
+    }
+    Ok(())
+}
+
+/// Describe how an answer differs from the expected one, a line at a time.
+pub(crate) fn diff(expected: &str, got: &Result<String, String>) -> String {
+    let mut diff = String::new();
+    for line in expected.lines() {
+        writeln!(diff, "  - {line}").unwrap();
+    }
+    match got {
+        Ok(answer) => {
+            for line in answer.lines() {
+                writeln!(diff, "  + {line}").unwrap();
+            }
+        }
+        Err(error) => {
+            writeln!(
+                diff,
+                "  + FAILED: {}",
+                error.lines().next().unwrap_or_default()
+            )
+            .unwrap();
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solver(day: u8, part: Part) -> Solver {
+        Solver {
+            day,
+            part,
+            package: format!("day-{day}{part}"),
+            args: vec![],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.record("default", &solver(10, Part::B), "81".to_owned());
+        answers.record("default", &solver(6, Part::A), "41".to_owned());
+        answers.record("alice", &solver(17, Part::A), "4,6,3,\"5\"".to_owned());
+        assert_eq!(
+            answers.record("default", &solver(6, Part::A), "42".to_owned()),
+            Some("41".to_owned())
+        );
+
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "\
+[alice.day-17]
+a = '4,6,3,\"5\"'
+
+[default.day-6]
+a = \"42\"
+
+[default.day-10]
+b = \"81\"
+"
+        );
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+        assert_eq!(
+            answers.expected("default", &solver(10, Part::B)),
+            Some("81")
+        );
+        assert_eq!(answers.expected("alice", &solver(10, Part::B)), None);
+    }
+
+    #[test]
+    fn rejects_malformed_stores() {
+        assert!(Answers::parse("[default.day-6]\nc = \"1\"").is_err());
+        assert!(Answers::parse("[default.six]\na = \"1\"").is_err());
+        assert!(Answers::parse("[default.day-6]\na = 1").is_err());
+        assert!(Answers::parse("[\"bob smith\".day-6]\na = \"1\"").is_err());
+    }
+
+    #[test]
+    fn diffs() {
+        assert_eq!(diff("41", &Ok("42".to_owned())), "  - 41\n  + 42\n");
+        assert_eq!(
+            diff(
+                "41",
+                &Err("exit status: 101: panicked\nbacktrace".to_owned())
+            ),
+            "  - 41\n  + FAILED: exit status: 101: panicked\n"
+        );
+    }
+}
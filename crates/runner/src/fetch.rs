@@ -0,0 +1,301 @@
+//! Download puzzle inputs into `inputs/`, so solvers have something to embed.
+//!
+//! Inputs never change once a puzzle is unlocked, so one that has already been
+//! downloaded is never fetched again, and downloads are spaced out so as not to hammer
+//! the server, even across separate runs.
+
+use std::{
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+
+/// The environment variable holding the session cookie of the account to fetch inputs for.
+pub(crate) const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The file, in the root of the workspace, to read the session cookie from if
+/// [`SESSION_VAR`] isn't set.
+pub(crate) const SESSION_FILE: &str = ".session";
+
+/// The environment variable holding the URL to fetch inputs from instead of [`DEFAULT_BASE_URL`].
+pub(crate) const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub(crate) const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The file, in `inputs/`, holding when the server was last asked for an input,
+/// so that one run of `fetch` straight after another still waits its turn.
+pub(crate) const LAST_REQUEST_FILE: &str = ".last-request";
+
+/// The year the puzzles are from.
+pub(crate) const YEAR: u16 = 2024;
+
+/// The shortest time to leave between two requests to the server.
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = concat!(
+    "advent-of-code-2024-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (input fetcher)"
+);
+
+/// Find the session cookie, from [`SESSION_VAR`] or else the [`SESSION_FILE`].
+pub(crate) fn session(root: &Path) -> anyhow::Result<String> {
+    if let Ok(session) = std::env::var(SESSION_VAR) {
+        return Ok(session.trim().to_owned());
+    }
+    let path = root.join(SESSION_FILE);
+    match std::fs::read_to_string(&path) {
+        Ok(session) => Ok(session.trim().to_owned()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => bail!(
+            "No session cookie: set {SESSION_VAR} or save it in {}",
+            path.display()
+        ),
+        Err(error) => Err(error).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+/// How an input was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fetched {
+    /// It had been downloaded before.
+    Cached,
+    Downloaded,
+}
+
+/// A client for the puzzle server, which downloads at most one input every [`MIN_INTERVAL`].
+pub(crate) struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    /// Where the time of the last request is kept (see [`LAST_REQUEST_FILE`]).
+    last_request_file: PathBuf,
+}
+
+impl Client {
+    pub(crate) fn new(base_url: &str, session: String, last_request_file: PathBuf) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            min_interval: MIN_INTERVAL,
+            last_request_file,
+        }
+    }
+
+    /// Make sure the input for `day` is at `path`, downloading it if it isn't there yet.
+    ///
+    /// Empty files don't count, since they can only be placeholders.
+    pub(crate) fn fetch(&self, year: u16, day: u8, path: &Path) -> anyhow::Result<Fetched> {
+        if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.download(year, day)?;
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)
+                .with_context(|| format!("Failed to create {}", directory.display()))?;
+        }
+        // Write to a temporary file first, so an interrupted download can't leave half an input.
+        let partial = PathBuf::from(format!("{}.partial", path.display()));
+        if let Err(error) =
+            std::fs::write(&partial, input).and_then(|()| std::fs::rename(&partial, path))
+        {
+            let _ = std::fs::remove_file(&partial);
+            return Err(error).with_context(|| format!("Failed to write {}", path.display()));
+        }
+        Ok(Fetched::Downloaded)
+    }
+
+    fn download(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        self.wait_for_turn()?;
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .with_context(|| format!("Failed to fetch {url}"))?;
+        match response.status().as_u16() {
+            200 => {}
+            404 => bail!("Day {day} of {year} hasn't unlocked yet ({url} was not found)"),
+            400 | 401 | 403 => bail!(
+                "The server refused to give out day {day}'s input: \
+                 is the session cookie right, and still valid?"
+            ),
+            status => bail!("Failed to fetch {url}: the server responded with status {status}"),
+        }
+        let input = response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("Failed to read the response from {url}"))?;
+        if input.is_empty() {
+            bail!("The server sent an empty input for day {day}");
+        }
+        Ok(input)
+    }
+
+    /// Sleep until [`MIN_INTERVAL`] has passed since the last request, by this run or any other,
+    /// and then record that another request is being made now.
+    fn wait_for_turn(&self) -> anyhow::Result<()> {
+        // A missing or garbled file just means there hasn't been a request we know of.
+        let last_request = std::fs::read_to_string(&self.last_request_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request {
+            // A request in the future means the clock has gone back, so wait the whole interval.
+            let elapsed = SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or(Duration::ZERO);
+            thread::sleep(self.min_interval.saturating_sub(elapsed));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("Expected the clock to be set after 1970")?;
+        if let Some(directory) = self.last_request_file.parent() {
+            std::fs::create_dir_all(directory)
+                .with_context(|| format!("Failed to create {}", directory.display()))?;
+        }
+        std::fs::write(&self.last_request_file, now.as_millis().to_string())
+            .with_context(|| format!("Failed to write {}", self.last_request_file.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    use std::time::Instant;
+
+    use super::*;
+
+    /// Serve `responses` in order from a local server, one per connection,
+    /// sending the request line and cookie of each request down the returned channel.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![];
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap().to_ascii_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if !line.contains(':') || line.starts_with("cookie:") {
+                        request.push(line);
+                    }
+                }
+                sender.send(request.join("\n")).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    fn scratch_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("runner-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn downloads_each_input_once() {
+        let (base_url, requests) = stub_server(vec![(200, "3   4\n4   3\n"), (200, "7 6 4\n")]);
+        let directory = scratch_directory("fetch");
+        let mut client = Client::new(
+            &base_url,
+            "abc123".to_owned(),
+            directory.join(LAST_REQUEST_FILE),
+        );
+        client.min_interval = Duration::from_millis(100);
+
+        let day_1 = directory.join("alice/day-1.txt");
+        let start = Instant::now();
+        assert_eq!(client.fetch(2024, 1, &day_1).unwrap(), Fetched::Downloaded);
+        assert_eq!(client.fetch(2024, 1, &day_1).unwrap(), Fetched::Cached);
+        assert_eq!(std::fs::read_to_string(&day_1).unwrap(), "3   4\n4   3\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            "get /2024/day/1/input http/1.1\ncookie: session=abc123"
+        );
+
+        // An empty placeholder isn't a real input, so it gets replaced.
+        let day_2 = directory.join("day-2.txt");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(&day_2, "").unwrap();
+        assert_eq!(client.fetch(2024, 2, &day_2).unwrap(), Fetched::Downloaded);
+        assert_eq!(std::fs::read_to_string(&day_2).unwrap(), "7 6 4\n");
+        assert!(start.elapsed() >= client.min_interval);
+        assert_eq!(
+            requests.recv().unwrap(),
+            "get /2024/day/2/input http/1.1\ncookie: session=abc123"
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let (base_url, _requests) = stub_server(vec![(404, "Not found"), (400, "Bad session")]);
+        let directory = scratch_directory("fetch-errors");
+        let mut client = Client::new(
+            &base_url,
+            "abc123".to_owned(),
+            directory.join(LAST_REQUEST_FILE),
+        );
+        client.min_interval = Duration::ZERO;
+
+        let path = directory.join("day-25.txt");
+        let error = client.fetch(2024, 25, &path).unwrap_err();
+        assert!(error.to_string().contains("hasn't unlocked yet"), "{error}");
+        let error = client.fetch(2024, 25, &path).unwrap_err();
+        assert!(error.to_string().contains("session cookie"), "{error}");
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn separate_clients_wait_their_turn() {
+        let (base_url, _requests) = stub_server(vec![(200, "1\n"), (200, "2\n")]);
+        let directory = scratch_directory("fetch-interval");
+        let min_interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        for day in [1, 2] {
+            let mut client = Client::new(
+                &base_url,
+                "abc123".to_owned(),
+                directory.join(LAST_REQUEST_FILE),
+            );
+            client.min_interval = min_interval;
+            let path = directory.join(format!("day-{day}.txt"));
+            assert_eq!(client.fetch(2024, day, &path).unwrap(), Fetched::Downloaded);
+        }
+        assert!(start.elapsed() >= min_interval);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,443 @@
+//! Build and run every day's solvers concurrently, and report how long each one took.
+//!
+//! Each solver is still its own binary, so the runner builds them all in release mode
+//! and then runs them as child processes on a fixed-size pool of worker threads.
+//!
+//! `record` stores the answers it gets in the answer store as the expected ones, and
+//! `check` reruns everything in the store, failing with a diff if any answer has changed.
+//! `fetch` downloads the inputs the solvers need.
+
+use std::{
+    num::NonZeroUsize,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+use answers::Answers;
+use anyhow::{bail, Context};
+use report::{Format, Record};
+use solver::{Execution, Part, Solver, DEFAULT_INPUT};
+
+mod answers;
+mod fetch;
+mod report;
+mod solver;
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1).peekable();
+    let root = solver::workspace_root();
+    // Fetching downloads inputs instead of running solvers, so it has nothing else in common.
+    if args.next_if(|arg| arg == "fetch").is_some() {
+        return fetch_inputs(&root, &Options::parse(args, "fetch", FETCH_OPTIONS)?);
+    }
+    let name = args
+        .next_if(|arg| matches!(arg.as_str(), "run" | "record" | "check"))
+        .unwrap_or_else(|| "run".to_owned());
+    let command = match name.as_str() {
+        "record" => Command::Record,
+        "check" => Command::Check,
+        _ => Command::Run,
+    };
+    let options = Options::parse(args, &name, ALL_OPTIONS)?;
+    if command == Command::Record && options.days.is_empty() {
+        bail!("Give the days whose answers have been accepted, such as `record 6b`");
+    }
+
+    let store = root.join(answers::FILE_NAME);
+    let mut answers = Answers::load(&store)?;
+    let solvers: Vec<Solver> = solver::discover(&root)?
+        .into_iter()
+        .filter(|solver| options.selects(solver.day, solver.part))
+        .collect();
+
+    // Recorded answers whose solver has been renamed or deleted, which `check` reports as failures.
+    let mut missing = vec![];
+    let runs: Vec<(Solver, String)> = match command {
+        // Check every input that has answers recorded, unless asked about one in particular.
+        Command::Check => {
+            let mut runs = vec![];
+            for (input, day, part, _) in answers.iter() {
+                if options.input.as_deref().is_some_and(|name| name != input)
+                    || !options.selects(day, part)
+                {
+                    continue;
+                }
+                match solvers
+                    .iter()
+                    .find(|solver| solver.day == day && solver.part == part)
+                {
+                    Some(solver) => runs.push((solver.clone(), input.to_owned())),
+                    None => missing.push(format!("day {day}{part} ({input})")),
+                }
+            }
+            if runs.is_empty() && !missing.is_empty() {
+                return check(&[], &answers, &missing);
+            }
+            runs
+        }
+        Command::Run | Command::Record => {
+            if solvers.is_empty() {
+                bail!("No solvers match the days given");
+            }
+            let input = options.input.as_deref().unwrap_or(DEFAULT_INPUT);
+            solvers
+                .iter()
+                .map(|solver| (solver.clone(), input.to_owned()))
+                .collect()
+        }
+    };
+    if runs.is_empty() {
+        bail!("No answers are recorded for the days and input given");
+    }
+    let built: Vec<Solver> = solvers
+        .into_iter()
+        .filter(|solver| runs.iter().any(|(run, _)| run == solver))
+        .collect();
+    let binaries = solver::build(&root, &built)?;
+
+    let start = Instant::now();
+    let paths: Vec<PathBuf> = runs
+        .iter()
+        .map(|(solver, input)| solver.input_path(&root, input))
+        .collect();
+    let jobs: Vec<(&Solver, &Path)> = runs
+        .iter()
+        .zip(&paths)
+        .map(|((solver, _), path)| (solver, path.as_path()))
+        .collect();
+    let executions = run_all(&jobs, &binaries, options.jobs, options.threads);
+    let elapsed = start.elapsed();
+
+    let records: Vec<Record> = runs
+        .into_iter()
+        .zip(&paths)
+        .zip(executions)
+        .map(|(((solver, input), path), execution)| Record {
+            correct: answers
+                .expected(&input, &solver)
+                .map(|expected| execution.answer.as_deref() == Ok(expected)),
+            input_hash: solver::input_hash(path),
+            solver,
+            input,
+            execution,
+        })
+        .collect();
+    report::print(&records, elapsed, options.format);
+
+    match command {
+        Command::Run => {}
+        Command::Record => record(&records, &mut answers, &store)?,
+        Command::Check => check(&records, &answers, &missing)?,
+    }
+
+    let failures = records
+        .iter()
+        .filter(|record| record.execution.answer.is_err())
+        .count();
+    if failures > 0 {
+        bail!("{failures} solver(s) failed");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Run the solvers and report on them.
+    Run,
+    /// Run the solvers and store their answers as the expected ones.
+    Record,
+    /// Run the solvers for every recorded answer and fail if any answer has changed.
+    Check,
+}
+
+/// Download the input for every day selected that doesn't have one yet.
+fn fetch_inputs(root: &Path, options: &Options) -> anyhow::Result<()> {
+    let mut days: Vec<u8> = if options.days.is_empty() {
+        solver::discover(root)?
+            .iter()
+            .map(|solver| solver.day)
+            .collect()
+    } else {
+        options.days.iter().map(|&(day, _)| day).collect()
+    };
+    days.sort_unstable();
+    days.dedup();
+
+    let base_url =
+        std::env::var(fetch::BASE_URL_VAR).unwrap_or_else(|_| fetch::DEFAULT_BASE_URL.to_owned());
+    let client = fetch::Client::new(
+        &base_url,
+        fetch::session(root)?,
+        root.join("inputs").join(fetch::LAST_REQUEST_FILE),
+    );
+    let input = options.input.as_deref().unwrap_or(DEFAULT_INPUT);
+    for day in days {
+        let path = solver::input_path(root, input, day);
+        match client.fetch(fetch::YEAR, day, &path)? {
+            fetch::Fetched::Cached => eprintln!("day {day}: already downloaded"),
+            fetch::Fetched::Downloaded => eprintln!("day {day}: downloaded {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Store every answer the solvers produced, saying which answers changed.
+fn record(records: &[Record], answers: &mut Answers, store: &Path) -> anyhow::Result<()> {
+    let mut recorded = 0;
+    for record in records {
+        let Ok(answer) = &record.execution.answer else {
+            continue;
+        };
+        if let Some(previous) = answers.record(&record.input, &record.solver, answer.clone()) {
+            if previous != *answer {
+                eprintln!(
+                    "{} ({}): replaced the expected answer\n{}",
+                    record.solver,
+                    record.input,
+                    answers::diff(&previous, &record.execution.answer)
+                );
+            }
+        }
+        recorded += 1;
+    }
+    answers.save(store)?;
+    eprintln!("Recorded {recorded} answer(s) in {}", store.display());
+    Ok(())
+}
+
+/// Print a diff for every answer that doesn't match the recorded one,
+/// and fail if any answer changed or had no solver to check it with.
+fn check(records: &[Record], answers: &Answers, missing: &[String]) -> anyhow::Result<()> {
+    let mismatches: Vec<&Record> = records
+        .iter()
+        .filter(|record| record.correct == Some(false))
+        .collect();
+    for record in &mismatches {
+        let expected = answers
+            .expected(&record.input, &record.solver)
+            .unwrap_or_default();
+        eprintln!(
+            "{} ({}):\n{}",
+            record.solver,
+            record.input,
+            answers::diff(expected, &record.execution.answer)
+        );
+    }
+    for solver in missing {
+        eprintln!("{solver}: no solver was found for this recorded answer");
+    }
+    let total = records.len() + missing.len();
+    if !mismatches.is_empty() || !missing.is_empty() {
+        bail!(
+            "{} of {total} answer(s) in {} differ or have no solver",
+            mismatches.len() + missing.len(),
+            answers::FILE_NAME
+        );
+    }
+    eprintln!("All {total} answer(s) match {}", answers::FILE_NAME);
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Options {
+    /// How many solvers to run at once.
+    jobs: usize,
+    /// How many threads each solver may use internally.
+    threads: usize,
+    /// Only run these days (and parts); run everything if this is empty.
+    days: Vec<(u8, Option<Part>)>,
+    /// The name of the input to run on; every recorded input when checking, or the default.
+    input: Option<String>,
+    format: Format,
+}
+
+/// Every option there is; all of them apply when running solvers.
+const ALL_OPTIONS: &[&str] = &["--jobs", "-j", "--threads", "--input", "--format"];
+
+/// The options that apply to `fetch`, which doesn't run any solvers.
+const FETCH_OPTIONS: &[&str] = &["--input"];
+
+impl Options {
+    /// Parse the arguments after the name of the command, failing on any option
+    /// that isn't in `accepted`.
+    fn parse(
+        args: impl Iterator<Item = String>,
+        command: &str,
+        accepted: &[&str],
+    ) -> anyhow::Result<Self> {
+        let cores = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let mut jobs = None;
+        let mut threads = None;
+        let mut days = vec![];
+        let mut input = None;
+        let mut format = Format::Table;
+
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            if ALL_OPTIONS.contains(&arg.as_str()) && !accepted.contains(&arg.as_str()) {
+                bail!("{arg} doesn't apply to `{command}`");
+            }
+            match arg.as_str() {
+                "--jobs" | "-j" => jobs = Some(parse_count(args.next(), &arg)?),
+                "--threads" => threads = Some(parse_count(args.next(), &arg)?),
+                "--input" => {
+                    let name = args
+                        .next()
+                        .context("Expected an input name after --input")?;
+                    answers::validate_input_name(&name)?;
+                    input = Some(name);
+                }
+                "--format" => {
+                    format = args
+                        .next()
+                        .context("Expected `table`, `json` or `csv` after --format")?
+                        .parse()?;
+                }
+                _ => days.push(parse_day(&arg)?),
+            }
+        }
+
+        let jobs = jobs.unwrap_or(cores);
+        Ok(Self {
+            jobs,
+            // Share the machine's cores out between the solvers running at once by default.
+            threads: threads.unwrap_or((cores / jobs).max(1)),
+            days,
+            input,
+            format,
+        })
+    }
+
+    fn selects(&self, day: u8, part: Part) -> bool {
+        self.days.is_empty()
+            || self.days.iter().any(|&(selected, selected_part)| {
+                selected == day && selected_part.is_none_or(|selected_part| selected_part == part)
+            })
+    }
+}
+
+fn parse_count(value: Option<String>, flag: &str) -> anyhow::Result<usize> {
+    let value = value.with_context(|| format!("Expected a number after {flag}"))?;
+    let count: NonZeroUsize = value
+        .parse()
+        .with_context(|| format!("Expected a positive number after {flag}, got {value:?}"))?;
+    Ok(count.get())
+}
+
+/// The days there are puzzles for.
+const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// Parse a day such as `6`, or a single part of a day such as `6b`.
+fn parse_day(arg: &str) -> anyhow::Result<(u8, Option<Part>)> {
+    let (day, part) = match arg.strip_suffix('a') {
+        Some(day) => (day, Some(Part::A)),
+        None => match arg.strip_suffix('b') {
+            Some(day) => (day, Some(Part::B)),
+            None => (arg, None),
+        },
+    };
+    let day = day
+        .parse()
+        .with_context(|| format!("Unknown argument {arg:?}: expected a day such as `6` or `6b`"))?;
+    if !DAYS.contains(&day) {
+        bail!("There is no day {day}: the puzzles run from day 1 to day 25");
+    }
+    Ok((day, part))
+}
+
+/// Run every solver on its input on a pool of `jobs` worker threads,
+/// returning the results in the same order.
+fn run_all(
+    runs: &[(&Solver, &Path)],
+    binaries: &Path,
+    jobs: usize,
+    threads: usize,
+) -> Vec<Execution> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(runs.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some((solver, input)) = runs.get(index) else {
+                    break;
+                };
+                let execution = solver.run(binaries, input, threads);
+                if sender.send((index, execution)).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<(usize, Execution)> = receiver.into_iter().collect();
+    results.sort_by_key(|(index, _)| *index);
+    results
+        .into_iter()
+        .map(|(_, execution)| execution)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> anyhow::Result<Options> {
+        Options::parse(args.iter().map(|&arg| arg.to_owned()), "run", ALL_OPTIONS)
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_day("6").unwrap(), (6, None));
+        assert_eq!(parse_day("25b").unwrap(), (25, Some(Part::B)));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26a").is_err());
+        assert!(parse_day("six").is_err());
+    }
+
+    #[test]
+    fn options() {
+        let options = parse(&["--input", "alice", "-j", "2", "6b", "7"]).unwrap();
+        assert_eq!(options.input.as_deref(), Some("alice"));
+        assert_eq!(options.jobs, 2);
+        assert!(options.selects(6, Part::B) && !options.selects(6, Part::A));
+        assert!(options.selects(7, Part::A));
+
+        // Input names end up in paths, so they mustn't be able to leave `inputs/`.
+        assert!(parse(&["--input", "../x"]).is_err());
+        assert!(parse(&["--input", "/tmp"]).is_err());
+        assert!(parse(&["--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn fetch_options() {
+        let fetch = |args: &[&str]| {
+            Options::parse(
+                args.iter().map(|&arg| arg.to_owned()),
+                "fetch",
+                FETCH_OPTIONS,
+            )
+        };
+        assert_eq!(
+            fetch(&["--input", "alice", "6"]).unwrap().input.as_deref(),
+            Some("alice")
+        );
+        for option in ["--jobs", "-j", "--threads", "--format"] {
+            let error = fetch(&[option, "2"]).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("{option} doesn't apply to `fetch`")
+            );
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::solver::{Execution, Solver};
+
+/// Everything we know about one run of a solver.
+#[derive(Debug)]
+pub(crate) struct Record {
+    pub(crate) solver: Solver,
+    /// The name of the input the solver ran on.
+    pub(crate) input: String,
+    pub(crate) execution: Execution,
+    /// Whether the answer matches the expected answer, if we know what that is.
+    pub(crate) correct: Option<bool>,
+    /// A fingerprint of the input the solver ran on, if the input could be read.
+    pub(crate) input_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    /// A table for people to read.
+    Table,
+    /// One JSON object per solver, one per line.
+    JsonLines,
+    Csv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::JsonLines),
+            "csv" => Ok(Self::Csv),
+            _ => anyhow::bail!("Unknown format {s:?}: expected `table`, `json` or `csv`"),
+        }
+    }
+}
+
+/// The columns of the machine-readable formats, in order.
+///
+/// Solvers are separate binaries, so `wall_time_us` covers the whole process:
+/// starting up, reading and parsing the input, and solving the puzzle.
+/// `parse_time_us` and `solve_time_us` are the phases as the solver timed them itself.
+const COLUMNS: [&str; 11] = [
+    "day",
+    "part",
+    "input",
+    "answer",
+    "error",
+    "correct",
+    "wall_time_us",
+    "cpu_time_us",
+    "parse_time_us",
+    "solve_time_us",
+    "input_hash",
+];
+
+/// A value in one of the machine-readable formats.
+enum Value {
+    Null,
+    Bool(bool),
+    Number(u128),
+    String(String),
+}
+
+impl Record {
+    fn values(&self) -> [Value; 11] {
+        let Record {
+            solver,
+            input,
+            execution,
+            correct,
+            input_hash,
+        } = self;
+        let string = |value: Option<&String>| value.cloned().map_or(Value::Null, Value::String);
+        let time = |time: Option<Duration>| {
+            time.map_or(Value::Null, |time| Value::Number(time.as_micros()))
+        };
+        [
+            Value::Number(u128::from(solver.day)),
+            Value::String(solver.part.to_string()),
+            Value::String(input.clone()),
+            string(execution.answer.as_ref().ok()),
+            string(execution.answer.as_ref().err()),
+            correct.map_or(Value::Null, Value::Bool),
+            Value::Number(execution.wall_time.as_micros()),
+            time(execution.cpu_time),
+            time(execution.timings.map(|timings| timings.parse_time)),
+            time(execution.timings.map(|timings| timings.solve_time)),
+            string(input_hash.as_ref()),
+        ]
+    }
+}
+
+/// Print the results in the given format.
+pub(crate) fn print(records: &[Record], elapsed: Duration, format: Format) {
+    match format {
+        Format::Table => print_table(records, elapsed),
+        Format::JsonLines => {
+            for record in records {
+                println!("{}", json_line(record));
+            }
+        }
+        Format::Csv => {
+            println!("{}", COLUMNS.join(","));
+            for record in records {
+                println!("{}", csv_row(record));
+            }
+        }
+    }
+}
+
+fn json_line(record: &Record) -> String {
+    let fields: Vec<String> = COLUMNS
+        .iter()
+        .zip(record.values())
+        .map(|(column, value)| {
+            let value = match value {
+                Value::Null => "null".to_owned(),
+                Value::Bool(value) => value.to_string(),
+                Value::Number(value) => value.to_string(),
+                Value::String(value) => json_string(&value),
+            };
+            format!("{}:{value}", json_string(column))
+        })
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", u32::from(c)).unwrap();
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_row(record: &Record) -> String {
+    let fields: Vec<String> = record
+        .values()
+        .into_iter()
+        .map(|value| match value {
+            Value::Null => String::new(),
+            Value::Bool(value) => value.to_string(),
+            Value::Number(value) => value.to_string(),
+            Value::String(value) => csv_field(&value),
+        })
+        .collect();
+    fields.join(",")
+}
+
+/// Quote a field if it contains anything that would confuse a CSV reader.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// How many of the slowest solvers to call out below the table.
+const SLOWEST_SHOWN: usize = 3;
+
+/// Print one row per solver, followed by the totals and the slowest solvers.
+fn print_table(records: &[Record], elapsed: Duration) {
+    println!(
+        "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10} {:>10}  Answer",
+        "Day", "Part", "Input", "Wall", "CPU", "Parse", "Solve"
+    );
+    for Record {
+        solver,
+        input,
+        execution,
+        correct,
+        ..
+    } in records
+    {
+        let answer = match (&execution.answer, correct) {
+            (Ok(answer), Some(false)) => format!("WRONG: {}", answer.replace('\n', " ")),
+            (Ok(answer), _) => answer.replace('\n', " "),
+            (Err(error), _) => format!("FAILED: {}", error.lines().next().unwrap_or_default()),
+        };
+        let time = |time: Option<Duration>| time.map_or_else(|| "-".to_owned(), format_duration);
+        println!(
+            "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10} {:>10}  {answer}",
+            solver.day,
+            solver.part,
+            input,
+            format_duration(execution.wall_time),
+            time(execution.cpu_time),
+            time(execution.timings.map(|timings| timings.parse_time)),
+            time(execution.timings.map(|timings| timings.solve_time)),
+        );
+    }
+
+    let total_wall: Duration = records
+        .iter()
+        .map(|record| record.execution.wall_time)
+        .sum();
+    let total_cpu: Option<Duration> = records.iter().map(|record| record.execution.cpu_time).sum();
+    println!();
+    println!(
+        "Total: {} wall, {} CPU, finished in {}",
+        format_duration(total_wall),
+        total_cpu.map_or_else(|| "unknown".to_owned(), format_duration),
+        format_duration(elapsed),
+    );
+
+    let mut slowest: Vec<&Record> = records.iter().collect();
+    slowest.sort_by_key(|record| std::cmp::Reverse(record.execution.wall_time));
+    let slowest: Vec<String> = slowest
+        .iter()
+        .take(SLOWEST_SHOWN)
+        .map(|record| {
+            format!(
+                "{} ({})",
+                record.solver,
+                format_duration(record.execution.wall_time)
+            )
+        })
+        .collect();
+    println!("Slowest: {}", slowest.join(", "));
+}
+
+/// Format a duration with a sensible unit and three significant figures or so.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds >= 1.0 {
+        format!("{seconds:.2}s")
+    } else if seconds >= 1e-3 {
+        format!("{:.2}ms", seconds * 1e3)
+    } else {
+        format!("{:.0}µs", seconds * 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utilities::timing::Timings;
+
+    use super::*;
+    use crate::solver::Part;
+
+    #[test]
+    fn machine_readable_formats() {
+        let record = Record {
+            solver: Solver {
+                day: 17,
+                part: Part::A,
+                package: "day-17".to_owned(),
+                args: vec!["a"],
+            },
+            input: "default".to_owned(),
+            execution: Execution {
+                answer: Ok("4,6,3,\"5\"".to_owned()),
+                wall_time: Duration::from_micros(1500),
+                cpu_time: None,
+                timings: Some(Timings {
+                    parse_time: Duration::from_micros(250),
+                    solve_time: Duration::from_micros(1200),
+                }),
+            },
+            correct: Some(true),
+            input_hash: None,
+        };
+        assert_eq!(
+            json_line(&record),
+            r#"{"day":17,"part":"a","input":"default","answer":"4,6,3,\"5\"","error":null,"correct":true,"wall_time_us":1500,"cpu_time_us":null,"parse_time_us":250,"solve_time_us":1200,"input_hash":null}"#
+        );
+        assert_eq!(
+            csv_row(&record),
+            r#"17,a,default,"4,6,3,""5""",,true,1500,,250,1200,"#
+        );
+        assert_eq!(json_string("a\tb\u{1}"), r#""a\tb\u0001""#);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+        assert_eq!(format_duration(Duration::from_micros(1234)), "1.23ms");
+        assert_eq!(format_duration(Duration::from_micros(56)), "56µs");
+    }
+}
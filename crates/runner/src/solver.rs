@@ -0,0 +1,320 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+use utilities::timing::Timings;
+
+/// The name of the input in `inputs/day-N.txt`, which solvers embed when they're built.
+pub(crate) const DEFAULT_INPUT: &str = "default";
+
+/// The path of a day's input with the given name.
+///
+/// The [`DEFAULT_INPUT`] is `inputs/day-N.txt`; any other input lives in a directory
+/// of its own, such as `inputs/alice/day-N.txt`.
+pub(crate) fn input_path(root: &Path, input: &str, day: u8) -> PathBuf {
+    let file_name = format!("day-{day}.txt");
+    if input == DEFAULT_INPUT {
+        root.join("inputs").join(file_name)
+    } else {
+        root.join("inputs").join(input).join(file_name)
+    }
+}
+
+/// The path to the root of the workspace.
+pub(crate) fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => f.write_str("a"),
+            Part::B => f.write_str("b"),
+        }
+    }
+}
+
+/// One part of one day's puzzle, and the binary that solves it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Solver {
+    pub(crate) day: u8,
+    pub(crate) part: Part,
+    /// The name of the workspace package holding the binary.
+    pub(crate) package: String,
+    /// Days that solve both parts in one binary choose between them with an argument.
+    pub(crate) args: Vec<&'static str>,
+}
+
+impl fmt::Display for Solver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}{}", self.day, self.part)
+    }
+}
+
+/// Find every solver in the workspace, sorted by day and then by part.
+///
+/// Packages are named `day-N` followed by the part (`day-4a`), or just `day-N` if
+/// one binary solves both parts, taking `a` or `b` as its first argument.
+/// Day 25 only ever has one part.
+pub(crate) fn discover(root: &Path) -> anyhow::Result<Vec<Solver>> {
+    let mut solvers = vec![];
+    for entry in std::fs::read_dir(root.join("crates"))? {
+        let package = entry?.file_name().to_string_lossy().into_owned();
+        let Some(name) = package.strip_prefix("day-") else {
+            continue;
+        };
+        let (day, part) = match name.strip_suffix('a') {
+            Some(day) => (day, Some(Part::A)),
+            None => match name.strip_suffix('b') {
+                Some(day) => (day, Some(Part::B)),
+                None => (name, None),
+            },
+        };
+        let day: u8 = day
+            .parse()
+            .with_context(|| format!("Unexpected package name {package:?}"))?;
+        match part {
+            Some(part) => solvers.push(Solver {
+                day,
+                part,
+                package,
+                args: vec![],
+            }),
+            None if day == 25 => solvers.push(Solver {
+                day,
+                part: Part::A,
+                package,
+                args: vec![],
+            }),
+            None => {
+                for (part, arg) in [(Part::A, "a"), (Part::B, "b")] {
+                    solvers.push(Solver {
+                        day,
+                        part,
+                        package: package.clone(),
+                        args: vec![arg],
+                    });
+                }
+            }
+        }
+    }
+    solvers.sort_by_key(|solver| (solver.day, solver.part));
+    Ok(solvers)
+}
+
+/// Build the binaries for all of `solvers` in release mode, returning the directory they're in.
+pub(crate) fn build(root: &Path, solvers: &[Solver]) -> anyhow::Result<PathBuf> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command
+        .current_dir(root)
+        .args(["build", "--release", "--quiet"]);
+    for solver in solvers {
+        command.args(["--package", &solver.package]);
+    }
+    if !command.status()?.success() {
+        bail!("Failed to build the solvers");
+    }
+    let target_dir =
+        std::env::var_os("CARGO_TARGET_DIR").map_or_else(|| root.join("target"), PathBuf::from);
+    Ok(target_dir.join("release"))
+}
+
+/// What happened when a solver was run.
+#[derive(Debug)]
+pub(crate) struct Execution {
+    /// The answer printed by the solver, or what went wrong.
+    pub(crate) answer: Result<String, String>,
+    pub(crate) wall_time: Duration,
+    /// The user and system time used by the solver, if the platform can tell us.
+    pub(crate) cpu_time: Option<Duration>,
+    /// How long the solver says it spent parsing and solving, if it got as far as saying.
+    pub(crate) timings: Option<Timings>,
+}
+
+impl Solver {
+    /// The path of the solver's input with the given name.
+    pub(crate) fn input_path(&self, root: &Path, input: &str) -> PathBuf {
+        input_path(root, input, self.day)
+    }
+
+    /// Run the solver's binary on `input`, allowing it to use `threads` threads internally.
+    pub(crate) fn run(&self, binaries: &Path, input: &Path, threads: usize) -> Execution {
+        let start = Instant::now();
+        let outcome = self.spawn(binaries, input, threads).and_then(wait);
+        let wall_time = start.elapsed();
+        match outcome {
+            Ok(Outcome {
+                status,
+                stdout,
+                stderr,
+                cpu_time,
+            }) => {
+                let (timings, stderr) = split_timings(&stderr);
+                let answer = if status.success() {
+                    Ok(stdout.trim_end().to_owned())
+                } else {
+                    Err(format!("{status}: {}", stderr.trim_end()))
+                };
+                Execution {
+                    answer,
+                    wall_time,
+                    cpu_time,
+                    timings,
+                }
+            }
+            Err(error) => Execution {
+                answer: Err(format!("Failed to run {}: {error}", self.package)),
+                wall_time,
+                cpu_time: None,
+                timings: None,
+            },
+        }
+    }
+
+    fn spawn(&self, binaries: &Path, input: &Path, threads: usize) -> io::Result<Child> {
+        let binary = format!("{}{}", self.package, std::env::consts::EXE_SUFFIX);
+        Command::new(binaries.join(binary))
+            .args(&self.args)
+            .env(utilities::input::INPUT_VAR, input)
+            .env(utilities::parallel::THREADS_VAR, threads.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+    }
+}
+
+/// Pick out the line of timings a solver prints on stderr, returning the rest of stderr with it.
+fn split_timings(stderr: &str) -> (Option<Timings>, String) {
+    let mut timings = None;
+    let mut rest = String::with_capacity(stderr.len());
+    for line in stderr.lines() {
+        match Timings::from_line(line) {
+            Some(line_timings) => timings = Some(line_timings),
+            None => {
+                rest.push_str(line);
+                rest.push('\n');
+            }
+        }
+    }
+    (timings, rest)
+}
+
+struct Outcome {
+    status: ExitStatus,
+    stdout: String,
+    stderr: String,
+    cpu_time: Option<Duration>,
+}
+
+/// Wait for the child to exit, collecting its output and how much CPU time it used.
+fn wait(mut child: Child) -> io::Result<Outcome> {
+    let mut stdout_pipe = child.stdout.take().expect("Expected stdout to be piped");
+    let mut stderr_pipe = child.stderr.take().expect("Expected stderr to be piped");
+
+    // Read both pipes at once, so that a child filling one of them can't block forever.
+    let (stdout, stderr) = thread::scope(|scope| {
+        let stderr = scope.spawn(move || {
+            let mut stderr = String::new();
+            stderr_pipe.read_to_string(&mut stderr).map(|_| stderr)
+        });
+        let mut stdout = String::new();
+        let stdout = stdout_pipe.read_to_string(&mut stdout).map(|_| stdout);
+        (
+            stdout,
+            stderr
+                .join()
+                .expect("Expected the stderr reader not to panic"),
+        )
+    });
+
+    let (status, cpu_time) = wait_with_cpu_time(&mut child)?;
+    Ok(Outcome {
+        status,
+        stdout: stdout?,
+        stderr: stderr?,
+        cpu_time,
+    })
+}
+
+#[cfg(unix)]
+fn wait_with_cpu_time(child: &mut Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = libc::pid_t::try_from(child.id()).map_err(io::Error::other)?;
+    let mut status = 0;
+    // SAFETY: `rusage` is plain old data, so all zeroes is a valid value.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `pid` belongs to a child we spawned and haven't waited for yet,
+    // and both out-pointers point to live, writable values of the right type.
+    let result = unsafe { libc::wait4(pid, &raw mut status, 0, &raw mut usage) };
+    if result == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let to_duration = |time: libc::timeval| {
+        Duration::from_secs(u64::try_from(time.tv_sec).unwrap_or(0))
+            + Duration::from_micros(u64::try_from(time.tv_usec).unwrap_or(0))
+    };
+    let cpu_time = to_duration(usage.ru_utime) + to_duration(usage.ru_stime);
+    Ok((ExitStatus::from_raw(status), Some(cpu_time)))
+}
+
+#[cfg(not(unix))]
+fn wait_with_cpu_time(child: &mut Child) -> io::Result<(ExitStatus, Option<Duration>)> {
+    Ok((child.wait()?, None))
+}
+
+/// A fingerprint of an input, so results can be matched up with the input they came from.
+/// Returns `None` if the input doesn't exist.
+pub(crate) fn input_hash(input: &Path) -> Option<String> {
+    let input = std::fs::read(input).ok()?;
+    Some(format!("{:016x}", fnv1a(&input)))
+}
+
+/// The 64-bit FNV-1a hash, which (unlike `std`'s hashers) is guaranteed never to change.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timings_are_taken_out_of_stderr() {
+        let (timings, rest) =
+            split_timings("Best sequence: -2,1\ntiming: parse_us=12 solve_us=345\n");
+        assert_eq!(
+            timings,
+            Some(Timings {
+                parse_time: Duration::from_micros(12),
+                solve_time: Duration::from_micros(345),
+            })
+        );
+        assert_eq!(rest, "Best sequence: -2,1\n");
+        assert_eq!(split_timings("panicked\n"), (None, "panicked\n".to_owned()));
+    }
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
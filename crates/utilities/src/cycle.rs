@@ -0,0 +1,182 @@
+//! Detecting when a deterministic sequence of states starts repeating itself.
+//!
+//! Every detector takes an initial state and a `step` function producing the next state.
+//! `step` returns `None` if the sequence ends (a guard walking off the map, say),
+//! in which case there is no cycle and the detector returns `None` too;
+//! state machines that run forever can just wrap every new state in `Some`.
+
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// Where a sequence of states starts repeating, and how often.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first state that is part of the cycle.
+    pub start: usize,
+    /// The number of steps it takes to get back to the same state once inside the cycle.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// Work out the state after `n` steps without simulating all of them.
+    ///
+    /// `initial` and `step` must be the same as those used to find the cycle.
+    pub fn state_at<S>(self, initial: S, mut step: impl FnMut(&S) -> Option<S>, n: usize) -> S {
+        let mut state = initial;
+        for _ in 0..self.equivalent_step(n) {
+            state = step(&state).expect("Expected a sequence with a cycle never to end");
+        }
+        state
+    }
+}
+
+/// Brent's algorithm: finds the cycle while only ever keeping two states in memory,
+/// usually with fewer calls to `step` than [`floyd`].
+pub fn brent<S: Clone + PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    // First find the period, by teleporting the tortoise to the hare
+    // every time the hare has taken a power-of-two number of steps.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare)?;
+        period += 1;
+    }
+
+    // Then find the start, by racing two states that are `period` steps apart.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, period })
+}
+
+/// Floyd's "tortoise and hare" algorithm: finds the cycle while only ever keeping
+/// two states in memory.
+pub fn floyd<S: Clone + PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    // The hare moves twice as fast as the tortoise, so they meet somewhere inside the cycle.
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    // The meeting point is a multiple of the period away from the start of the sequence,
+    // so moving one state back to the beginning and stepping both together finds the start.
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut period = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        period += 1;
+    }
+
+    Some(Cycle { start, period })
+}
+
+/// Finds the cycle by remembering every state seen so far.
+///
+/// This uses more memory than [`brent`] or [`floyd`], but never calls `step`
+/// more than once per state, which matters when stepping is expensive.
+pub fn find_with_hashing<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut seen = FxHashMap::default();
+    let mut state = initial;
+    for index in 0.. {
+        if let Some(start) = seen.insert(state.clone(), index) {
+            return Some(Cycle {
+                start,
+                period: index - start,
+            });
+        }
+        state = step(&state)?;
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, 5, 6, 7, 3, ...
+    fn rho() -> impl Fn(&u32) -> Option<u32> {
+        |state| Some(if *state == 7 { 3 } else { state + 1 })
+    }
+
+    #[test]
+    fn detectors_agree() {
+        let expected = Some(Cycle {
+            start: 3,
+            period: 5,
+        });
+        assert_eq!(brent(0, rho()), expected);
+        assert_eq!(floyd(0, rho()), expected);
+        assert_eq!(find_with_hashing(0, rho()), expected);
+
+        let pure_cycle = Some(Cycle {
+            start: 0,
+            period: 5,
+        });
+        assert_eq!(brent(3, rho()), pure_cycle);
+        assert_eq!(floyd(3, rho()), pure_cycle);
+        assert_eq!(find_with_hashing(3, rho()), pure_cycle);
+    }
+
+    #[test]
+    fn sequences_that_end() {
+        let ends = |state: &u32| (*state < 10).then_some(state + 1);
+        assert_eq!(brent(0, ends), None);
+        assert_eq!(floyd(0, ends), None);
+        assert_eq!(find_with_hashing(0, ends), None);
+    }
+
+    #[test]
+    fn extrapolation() {
+        let cycle = brent(0, rho()).unwrap();
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 5);
+        assert_eq!(cycle.state_at(0, rho(), 1_000_000_000), 5);
+        assert_eq!(cycle.state_at(0, rho(), 13), 3);
+    }
+}
@@ -0,0 +1,116 @@
+//! Arithmetic on the decimal (or other base) digits of unsigned integers.
+//!
+//! Zero is treated as having a single digit throughout, unlike `0u64.ilog10()`, which panics.
+//! Anything that could overflow returns an `Option`.
+
+/// The number of digits in `n` when written in base 10.
+pub fn count(n: u64) -> u32 {
+    count_in_base(n, 10)
+}
+
+/// The number of digits in `n` when written in the given base.
+pub fn count_in_base(n: u64, base: u64) -> u32 {
+    assert!(base >= 2, "Expected a base of at least 2, got {base}");
+    n.checked_ilog(base).map_or(1, |log| log + 1)
+}
+
+/// Split a number with an even number of digits into the number formed by its first half
+/// and the number formed by its second half, e.g. `1234` into `(12, 34)` and `1000` into `(10, 0)`.
+///
+/// Returns `None` if the number has an odd number of digits.
+pub fn split_halves(n: u64) -> Option<(u64, u64)> {
+    let digits = count(n);
+    if digits % 2 != 0 {
+        return None;
+    }
+    let divisor = 10u64.pow(digits / 2);
+    Some((n / divisor, n % divisor))
+}
+
+/// Write `right` directly after `left`, e.g. `12` and `345` become `12345`.
+///
+/// Returns `None` if the result would overflow a `u64`.
+pub fn concatenate(left: u64, right: u64) -> Option<u64> {
+    10u64
+        .checked_pow(count(right))
+        .and_then(|multiplier| left.checked_mul(multiplier))
+        .and_then(|shifted| shifted.checked_add(right))
+}
+
+/// The inverse of [`concatenate`]: if the digits of `n` end with the digits of `suffix`,
+/// return the number formed by the remaining digits, e.g. `12345` with the suffix `345` is `12`.
+///
+/// If `n` and `suffix` are equal, the remaining number is `0`.
+pub fn strip_suffix(n: u64, suffix: u64) -> Option<u64> {
+    let divisor = 10u64.checked_pow(count(suffix))?;
+    (n % divisor == suffix).then_some(n / divisor)
+}
+
+/// Iterate over the digits of `n` in base 10, most significant first.
+pub fn digits(n: u64) -> Digits {
+    digits_in_base(n, 10)
+}
+
+/// Iterate over the digits of `n` in the given base, most significant first.
+pub fn digits_in_base(n: u64, base: u64) -> Digits {
+    let divisor = base.pow(count_in_base(n, base) - 1);
+    Digits {
+        remaining: n,
+        divisor,
+        base,
+    }
+}
+
+/// Iterator returned by [`digits`] and [`digits_in_base`].
+#[derive(Debug, Clone)]
+pub struct Digits {
+    remaining: u64,
+    divisor: u64,
+    base: u64,
+}
+
+impl Iterator for Digits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.divisor == 0 {
+            return None;
+        }
+        let digit = self.remaining / self.divisor;
+        self.remaining %= self.divisor;
+        self.divisor /= self.base;
+        Some(digit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_has_one_digit() {
+        assert_eq!(count(0), 1);
+        assert_eq!(concatenate(12, 0), Some(120));
+        assert_eq!(concatenate(0, 0), Some(0));
+        assert_eq!(strip_suffix(120, 0), Some(12));
+        assert_eq!(digits(0).collect::<Vec<_>>(), [0]);
+        assert_eq!(split_halves(0), None);
+        assert_eq!(split_halves(10), Some((1, 0)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(concatenate(u64::MAX, 1), None);
+        assert_eq!(count(u64::MAX), 20);
+        assert_eq!(split_halves(u64::MAX), Some((1_844_674_407, 3_709_551_615)));
+    }
+
+    #[test]
+    fn other_bases() {
+        assert_eq!(count_in_base(8, 2), 4);
+        assert_eq!(digits_in_base(0o755, 8).collect::<Vec<_>>(), [7, 5, 5]);
+        assert_eq!(digits(90_210).collect::<Vec<_>>(), [9, 0, 2, 1, 0]);
+        assert_eq!(strip_suffix(12_345, 45), Some(123));
+        assert_eq!(strip_suffix(12_345, 44), None);
+    }
+}
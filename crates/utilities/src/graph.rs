@@ -0,0 +1,243 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Opaque handle for a node in an [`UndirectedGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+/// An undirected graph whose nodes are identified by string names.
+///
+/// Names are interned when they are first seen, so the algorithms on the graph
+/// only ever deal with cheap, `Copy` [`NodeId`]s. Use [`UndirectedGraph::name`]
+/// to get the name back out again.
+#[derive(Debug, Clone, Default)]
+pub struct UndirectedGraph {
+    names: Vec<Box<str>>,
+    ids: FxHashMap<Box<str>, NodeId>,
+    adjacency: Vec<FxHashSet<NodeId>>,
+}
+
+impl UndirectedGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the ID for the node called `name`, adding a new node with no edges if necessary.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(Box::from(name));
+        self.ids.insert(Box::from(name), id);
+        self.adjacency.push(FxHashSet::default());
+        id
+    }
+
+    /// Connect the nodes called `a` and `b`, adding them if necessary.
+    ///
+    /// A node is never its own neighbour, so an edge from a node to itself only adds the node.
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        let a = self.intern(a);
+        let b = self.intern(b);
+        if a == b {
+            return;
+        }
+        self.adjacency[a.0].insert(b);
+        self.adjacency[b.0].insert(a);
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id.0]
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.names.len()).map(NodeId)
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> &FxHashSet<NodeId> {
+        &self.adjacency[id.0]
+    }
+
+    pub fn are_adjacent(&self, a: NodeId, b: NodeId) -> bool {
+        self.adjacency[a.0].contains(&b)
+    }
+
+    /// Iterate over every set of three mutually connected nodes.
+    ///
+    /// Each triangle is yielded exactly once, with its nodes in ascending order of ID.
+    pub fn triangles(&self) -> impl Iterator<Item = [NodeId; 3]> + '_ {
+        self.nodes().flat_map(move |a| {
+            self.neighbours(a)
+                .iter()
+                .copied()
+                .filter(move |b| a < *b)
+                .flat_map(move |b| {
+                    self.neighbours(b)
+                        .iter()
+                        .copied()
+                        .filter(move |c| b < *c && self.are_adjacent(a, *c))
+                        .map(move |c| [a, b, c])
+                })
+        })
+    }
+
+    /// Find a largest set of nodes that are all connected to each other.
+    ///
+    /// This uses the Bron–Kerbosch algorithm with pivoting, and also stops exploring
+    /// a branch as soon as it can't possibly beat the largest clique found so far.
+    pub fn maximum_clique(&self) -> Vec<NodeId> {
+        let mut best = vec![];
+        self.bron_kerbosch(
+            &mut vec![],
+            self.nodes().collect(),
+            FxHashSet::default(),
+            &mut best,
+        );
+        best
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<NodeId>,
+        mut candidates: FxHashSet<NodeId>,
+        mut excluded: FxHashSet<NodeId>,
+        best: &mut Vec<NodeId>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > best.len() {
+                best.clone_from(clique);
+            }
+            return;
+        }
+        if clique.len() + candidates.len() <= best.len() {
+            return;
+        }
+
+        // Any maximal clique must include either the pivot or one of its non-neighbours,
+        // so choosing the pivot with the most neighbours among the candidates
+        // leaves the fewest branches to explore.
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|node| self.neighbours(*node).intersection(&candidates).count())
+            .expect("Expected candidates to be non-empty");
+        let pivot_neighbours = self.neighbours(pivot);
+        let branches: Vec<NodeId> = candidates
+            .iter()
+            .copied()
+            .filter(|node| !pivot_neighbours.contains(node))
+            .collect();
+
+        for node in branches {
+            let neighbours = self.neighbours(node);
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours).copied().collect(),
+                excluded.intersection(neighbours).copied().collect(),
+                best,
+            );
+            clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, S)> for UndirectedGraph {
+    fn from_iter<I: IntoIterator<Item = (S, S)>>(iter: I) -> Self {
+        let mut graph = Self::default();
+        for (a, b) in iter {
+            graph.add_edge(a.as_ref(), b.as_ref());
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(graph: &UndirectedGraph, ids: impl IntoIterator<Item = NodeId>) -> Vec<String> {
+        let mut names: Vec<String> = ids
+            .into_iter()
+            .map(|id| graph.name(id).to_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn edges_are_symmetric() {
+        let mut graph = UndirectedGraph::new();
+        graph.add_edge("kh", "tc");
+        graph.add_edge("tc", "kh");
+        graph.add_edge("kh", "qp");
+        let kh = graph.id("kh").unwrap();
+        let tc = graph.id("tc").unwrap();
+        let qp = graph.id("qp").unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.intern("kh"), kh);
+        assert!(graph.are_adjacent(kh, tc) && graph.are_adjacent(tc, kh));
+        assert!(!graph.are_adjacent(tc, qp));
+        assert_eq!(
+            names(&graph, graph.neighbours(kh).iter().copied()),
+            ["qp", "tc"]
+        );
+        assert_eq!(names(&graph, graph.neighbours(tc).iter().copied()), ["kh"]);
+        assert_eq!(graph.id("zz"), None);
+    }
+
+    #[test]
+    fn cliques() {
+        // A, B, C and D are all connected to each other; E only joins a triangle with A and B.
+        let graph: UndirectedGraph = [
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("e", "a"),
+            ("e", "b"),
+            ("f", "e"),
+        ]
+        .into_iter()
+        .collect();
+
+        let triangles: Vec<Vec<String>> = graph
+            .triangles()
+            .map(|triangle| names(&graph, triangle))
+            .collect();
+        assert_eq!(triangles.len(), 5);
+        assert!(triangles.contains(&vec!["a".into(), "b".into(), "e".into()]));
+
+        assert_eq!(names(&graph, graph.maximum_clique()), ["a", "b", "c", "d"]);
+        assert!(UndirectedGraph::new().maximum_clique().is_empty());
+    }
+
+    #[test]
+    fn self_edges_are_ignored() {
+        let graph: UndirectedGraph = [("a", "a"), ("a", "b"), ("b", "b"), ("c", "c")]
+            .into_iter()
+            .collect();
+        let a = graph.id("a").unwrap();
+        let c = graph.id("c").unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert!(!graph.are_adjacent(a, a));
+        assert!(graph.neighbours(c).is_empty());
+        assert_eq!(graph.triangles().count(), 0);
+        assert_eq!(names(&graph, graph.maximum_clique()), ["a", "b"]);
+    }
+}
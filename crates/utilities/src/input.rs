@@ -0,0 +1,24 @@
+//! Loading a solver's puzzle input.
+//!
+//! Solvers embed `inputs/day-N.txt` at compile time. Setting [`INPUT_VAR`] to the path
+//! of another input file makes them read that at runtime instead, so the same binary
+//! can be checked against inputs from several people's accounts without rebuilding it.
+
+use std::path::Path;
+
+/// The environment variable holding the path of an input to use instead of the embedded one.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Return the input named by [`INPUT_VAR`] if it is set, or the embedded input otherwise.
+///
+/// Panics if the variable is set but the file can't be read.
+pub fn with_override(embedded: &'static str) -> &'static str {
+    let Some(path) = std::env::var_os(INPUT_VAR) else {
+        return embedded;
+    };
+    let input = std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Failed to read {}: {error}", Path::new(&path).display()));
+    // A solver only ever reads one input, so leaking it costs nothing
+    // and means solvers can keep treating their input as `&'static str`.
+    input.leak()
+}
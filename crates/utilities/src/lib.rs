@@ -1 +1,14 @@
-pub mod maps;
\ No newline at end of file
+pub mod cycle;
+pub mod digits;
+pub mod graph;
+pub mod input;
+pub mod maps;
+pub mod math;
+pub mod parallel;
+pub mod parse;
+pub mod pattern;
+pub mod plane;
+pub mod prng;
+pub mod timing;
+pub mod trie;
+pub mod wordsearch;
@@ -1,12 +1,25 @@
 use std::{
     num::NonZeroU16,
-    ops::{Deref, DerefMut},
+    ops::{Add, Deref, DerefMut, Mul, Neg, Sub},
     str::FromStr,
 };
 
-use anyhow::{anyhow, Context};
+use anyhow::anyhow;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
+use crate::math;
+
+mod hex;
+mod three_d;
+
+pub use hex::{
+    FlatHexCompass, Hex, HexCompass, HexGrid, PointyHexCompass, ALL_FLAT_HEX_DIRECTIONS,
+    ALL_POINTY_HEX_DIRECTIONS,
+};
+pub use three_d::{
+    Axis, Cuboid, Delta3, Grid3, Point3, SixPointCompass, ALL_SIX_COMPASS_DIRECTIONS,
+};
+
 #[derive(Debug)]
 pub struct Grid<const MAX_COORDINATE: u16, T>(FxHashMap<Point<MAX_COORDINATE>, T>);
 
@@ -33,9 +46,223 @@ impl<const MAX_COORDINATE: u16, T> DerefMut for Grid<MAX_COORDINATE, T> {
     }
 }
 
+impl<const MAX_COORDINATE: u16, T> Grid<MAX_COORDINATE, T> {
+    /// Iterate over the orthogonal neighbours of `point` that are present in the grid,
+    /// along with the direction of each neighbour and the value stored there.
+    pub fn neighbours(
+        &self,
+        point: Point<MAX_COORDINATE>,
+    ) -> impl Iterator<Item = (FourPointCompass, Point<MAX_COORDINATE>, &T)> + '_ {
+        point.neighbours4().filter_map(|(direction, neighbour)| {
+            self.get(&neighbour)
+                .map(|value| (direction, neighbour, value))
+        })
+    }
+
+    /// Like [`Grid::neighbours`], but including diagonal neighbours.
+    pub fn neighbours8(
+        &self,
+        point: Point<MAX_COORDINATE>,
+    ) -> impl Iterator<Item = (EightPointCompass, Point<MAX_COORDINATE>, &T)> + '_ {
+        point.neighbours8().filter_map(|(direction, neighbour)| {
+            self.get(&neighbour)
+                .map(|value| (direction, neighbour, value))
+        })
+    }
+
+    /// Like [`Grid::neighbours`], but only yielding neighbours whose value passes `is_passable`.
+    pub fn passable_neighbours<'a>(
+        &'a self,
+        point: Point<MAX_COORDINATE>,
+        is_passable: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (FourPointCompass, Point<MAX_COORDINATE>, &'a T)> + 'a {
+        self.neighbours(point)
+            .filter(move |(_, _, value)| is_passable(value))
+    }
+}
+
+/// Row and column iteration, and geometric transforms.
+///
+/// The grid doesn't record its own dimensions, so the width and height are taken to be
+/// the largest x and y coordinates of any point present in the grid.
+/// Transforms return a new grid, leaving `self` untouched.
+impl<const MAX_COORDINATE: u16, T> Grid<MAX_COORDINATE, T> {
+    /// The largest x coordinate of any point in the grid, or 0 if the grid is empty.
+    pub fn width(&self) -> usize {
+        self.keys().map(|point| point.x()).max().unwrap_or(0)
+    }
+
+    /// The largest y coordinate of any point in the grid, or 0 if the grid is empty.
+    pub fn height(&self) -> usize {
+        self.keys().map(|point| point.y()).max().unwrap_or(0)
+    }
+
+    /// Iterate from west to east over the points in row `y` that are present in the grid.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_ {
+        self.row_within(y, self.width())
+    }
+
+    /// Iterate from north to south over the points in column `x` that are present in the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_ {
+        self.column_within(x, self.height())
+    }
+
+    /// Iterate over the rows of the grid from north to south.
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_> + '_ {
+        let width = self.width();
+        (1..=self.height()).map(move |y| self.row_within(y, width))
+    }
+
+    /// Iterate over the columns of the grid from west to east.
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_> + '_ {
+        let height = self.height();
+        (1..=self.width()).map(move |x| self.column_within(x, height))
+    }
+
+    /// Finding the width means scanning every point, so callers that visit
+    /// several rows find it once and pass it in.
+    fn row_within(
+        &self,
+        y: usize,
+        width: usize,
+    ) -> impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_ {
+        (1..=width).filter_map(move |x| self.entry_at(x, y))
+    }
+
+    fn column_within(
+        &self,
+        x: usize,
+        height: usize,
+    ) -> impl Iterator<Item = (Point<MAX_COORDINATE>, &T)> + '_ {
+        (1..=height).filter_map(move |y| self.entry_at(x, y))
+    }
+
+    fn entry_at(&self, x: usize, y: usize) -> Option<(Point<MAX_COORDINATE>, &T)> {
+        let point = Point::try_from((x, y)).ok()?;
+        self.get(&point).map(|value| (point, value))
+    }
+}
+
+impl<const MAX_COORDINATE: u16, T: Clone> Grid<MAX_COORDINATE, T> {
+    /// Swap the x and y axes, reflecting the grid in its leading diagonal.
+    #[must_use]
+    pub fn transposed(&self) -> Self {
+        self.remapped(|x, y| (y, x))
+    }
+
+    /// Rotate the grid by 90 degrees clockwise, so that the west column becomes the north row.
+    #[must_use]
+    pub fn rotated_clockwise(&self) -> Self {
+        let height = self.height();
+        self.remapped(|x, y| (height + 1 - y, x))
+    }
+
+    /// Rotate the grid by 90 degrees anticlockwise, so that the north row becomes the west column.
+    #[must_use]
+    pub fn rotated_anticlockwise(&self) -> Self {
+        let width = self.width();
+        self.remapped(|x, y| (y, width + 1 - x))
+    }
+
+    /// Mirror the grid from east to west.
+    #[must_use]
+    pub fn flipped_horizontally(&self) -> Self {
+        let width = self.width();
+        self.remapped(|x, y| (width + 1 - x, y))
+    }
+
+    /// Mirror the grid from north to south.
+    #[must_use]
+    pub fn flipped_vertically(&self) -> Self {
+        let height = self.height();
+        self.remapped(|x, y| (x, height + 1 - y))
+    }
+
+    /// The rectangular window of the grid with corners `top_left` and `bottom_right` (inclusive),
+    /// moved so that `top_left` becomes the point `(1, 1)`.
+    #[must_use]
+    pub fn cropped(
+        &self,
+        top_left: Point<MAX_COORDINATE>,
+        bottom_right: Point<MAX_COORDINATE>,
+    ) -> Self {
+        let mut grid = Self::default();
+        for (point, value) in self.iter() {
+            if (top_left.x()..=bottom_right.x()).contains(&point.x())
+                && (top_left.y()..=bottom_right.y()).contains(&point.y())
+            {
+                let new_point =
+                    Point::try_from((point.x() + 1 - top_left.x(), point.y() + 1 - top_left.y()))
+                        .expect("Expected a cropped point to still be on the grid");
+                grid.insert(new_point, value.clone());
+            }
+        }
+        grid
+    }
+
+    /// Repeat the grid `across` times from west to east and `down` times from north to south.
+    ///
+    /// Fails if the tiled grid would not fit within `MAX_COORDINATE`.
+    pub fn tiled(&self, across: usize, down: usize) -> anyhow::Result<Self> {
+        let (width, height) = (self.width(), self.height());
+        let mut grid = Self::default();
+        for tile_x in 0..across {
+            for tile_y in 0..down {
+                for (point, value) in self.iter() {
+                    let new_point =
+                        Point::try_from((tile_x * width + point.x(), tile_y * height + point.y()))?;
+                    grid.insert(new_point, value.clone());
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Widen the grid by replacing every tile with the `N` tiles returned by `expand`,
+    /// laid out from west to east.
+    ///
+    /// Fails if the widened grid would not fit within `MAX_COORDINATE`.
+    pub fn widened<const N: usize>(&self, expand: impl Fn(&T) -> [T; N]) -> anyhow::Result<Self> {
+        let mut grid = Self::default();
+        for (point, value) in self.iter() {
+            for (offset, new_value) in expand(value).into_iter().enumerate() {
+                let new_point = Point::try_from(((point.x() - 1) * N + offset + 1, point.y()))?;
+                grid.insert(new_point, new_value);
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Build a new grid by moving every point to the coordinates returned by `remap`,
+    /// which must stay within the grid's bounds.
+    fn remapped(&self, remap: impl Fn(usize, usize) -> (usize, usize)) -> Self {
+        let mut grid = Self::default();
+        for (point, value) in self.iter() {
+            let new_point = Point::try_from(remap(point.x(), point.y()))
+                .expect("Expected a transformed point to still be on the grid");
+            grid.insert(new_point, value.clone());
+        }
+        grid
+    }
+}
+
+impl<const MAX_COORDINATE: u16, T> IntoIterator for Grid<MAX_COORDINATE, T> {
+    type Item = (Point<MAX_COORDINATE>, T);
+    type IntoIter = std::collections::hash_map::IntoIter<Point<MAX_COORDINATE>, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 impl<const MAX_COORDINATE: u16, T> FromStr for Grid<MAX_COORDINATE, T>
 where
-    T: TryFrom<char, Error = anyhow::Error>,
+    T: TryFrom<char>,
+    T::Error: Into<anyhow::Error>,
 {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -43,7 +270,7 @@ where
         for (zero_based_y, line) in s.lines().enumerate() {
             for (zero_based_x, c) in line.char_indices() {
                 let point = Point::try_from((zero_based_x + 1, zero_based_y + 1))?;
-                let value = T::try_from(c)?;
+                let value = T::try_from(c).map_err(Into::into)?;
                 grid.insert(point, value);
             }
         }
@@ -75,10 +302,8 @@ impl<const MAX_COORDINATE: u16> Coordinate<MAX_COORDINATE> {
         self.get().checked_sub(other).and_then(Self::new)
     }
 
-    fn difference_from(self, other: Coordinate<MAX_COORDINATE>) -> anyhow::Result<i32> {
-        i32::from(self.get())
-            .checked_sub_unsigned(u32::from(other.get()))
-            .context("Expected values to fit into an i32")
+    fn difference_from(self, other: Coordinate<MAX_COORDINATE>) -> i32 {
+        i32::from(self.get()) - i32::from(other.get())
     }
 
     fn try_apply_delta(self, delta: i32) -> Option<Self> {
@@ -131,6 +356,21 @@ impl<const MAX_COORDINATE: u16> Point<MAX_COORDINATE> {
         }
     }
 
+    /// Iterate over the points directly north, east, south and west of this one,
+    /// skipping any that would fall off the edge of the grid.
+    pub fn neighbours4(self) -> impl Iterator<Item = (FourPointCompass, Self)> {
+        ALL_FOUR_COMPASS_DIRECTIONS
+            .iter()
+            .filter_map(move |&direction| self.shift(direction).map(|point| (direction, point)))
+    }
+
+    /// Like [`Point::neighbours4`], but including the four diagonal neighbours.
+    pub fn neighbours8(self) -> impl Iterator<Item = (EightPointCompass, Self)> {
+        ALL_EIGHT_COMPASS_DIRECTIONS
+            .iter()
+            .filter_map(move |&direction| self.shift(direction).map(|point| (direction, point)))
+    }
+
     pub fn shift_n(self, direction: EightPointCompass, n: u16) -> Option<Self> {
         let mut point = self;
         for _ in 0..n {
@@ -139,6 +379,44 @@ impl<const MAX_COORDINATE: u16> Point<MAX_COORDINATE> {
         Some(point)
     }
 
+    pub fn manhattan_distance(self, other: Self) -> u32 {
+        u32::from(self.x.get().abs_diff(other.x.get()))
+            + u32::from(self.y.get().abs_diff(other.y.get()))
+    }
+
+    /// The (one-based) x coordinate of the point.
+    pub fn x(self) -> usize {
+        usize::from(self.x.get())
+    }
+
+    /// The (one-based) y coordinate of the point.
+    pub fn y(self) -> usize {
+        usize::from(self.y.get())
+    }
+
+    /// The number of steps between two points if diagonal steps are allowed.
+    pub fn chebyshev_distance(self, other: Self) -> u32 {
+        u32::from(
+            self.x
+                .get()
+                .abs_diff(other.x.get())
+                .max(self.y.get().abs_diff(other.y.get())),
+        )
+    }
+
+    /// Iterate over the grid points lying exactly on the straight line from `self` to `end`,
+    /// including both `self` and `end`.
+    ///
+    /// Every point between two points on the grid is also on the grid,
+    /// so unlike [`Point::shift`] this never needs to return `None`.
+    pub fn line_to(self, end: Self) -> Line<MAX_COORDINATE> {
+        Line {
+            next: Some(self),
+            end,
+            step: (end - self).primitive(),
+        }
+    }
+
     pub fn apply_delta(self, delta: Delta) -> Option<Self> {
         let Delta { x_delta, y_delta } = delta;
         let Point { x, y } = self;
@@ -150,6 +428,17 @@ impl<const MAX_COORDINATE: u16> Point<MAX_COORDINATE> {
     }
 }
 
+impl<const MAX_COORDINATE: u16> Sub for Point<MAX_COORDINATE> {
+    type Output = Delta;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Delta {
+            x_delta: self.x.difference_from(rhs.x),
+            y_delta: self.y.difference_from(rhs.y),
+        }
+    }
+}
+
 impl<const MAX_COORDINATE: u16> TryFrom<(usize, usize)> for Point<MAX_COORDINATE> {
     type Error = anyhow::Error;
 
@@ -169,6 +458,20 @@ pub struct Delta {
 }
 
 impl Delta {
+    pub const ZERO: Delta = Delta::new(0, 0);
+
+    pub const fn new(x_delta: i32, y_delta: i32) -> Self {
+        Self { x_delta, y_delta }
+    }
+
+    pub const fn x_delta(self) -> i32 {
+        self.x_delta
+    }
+
+    pub const fn y_delta(self) -> i32 {
+        self.y_delta
+    }
+
     #[must_use]
     pub fn reversed(self) -> Delta {
         let Delta { x_delta, y_delta } = self;
@@ -177,24 +480,156 @@ impl Delta {
             y_delta: -y_delta,
         }
     }
+
+    /// The smallest delta pointing in the same direction as this one,
+    /// found by dividing both components by their greatest common divisor.
+    ///
+    /// Stepping by the primitive delta visits every grid point on the line, not just some of them.
+    /// The zero delta is its own primitive.
+    #[must_use]
+    pub fn primitive(self) -> Delta {
+        let Delta { x_delta, y_delta } = self;
+        let divisor = math::gcd(
+            u64::from(x_delta.unsigned_abs()),
+            u64::from(y_delta.unsigned_abs()),
+        );
+        if divisor == 0 {
+            return self;
+        }
+        let divisor = i64::try_from(divisor).expect("Expected the divisor to fit into an i64");
+        let divide = |component: i32| {
+            i32::try_from(i64::from(component) / divisor)
+                .expect("Expected dividing by a common factor to keep the component in range")
+        };
+        Delta {
+            x_delta: divide(x_delta),
+            y_delta: divide(y_delta),
+        }
+    }
+
+    /// Rotate the delta by 90 degrees clockwise, so that a delta pointing north ends up pointing east.
+    ///
+    /// The y axis points south, as it does everywhere else in this module.
+    #[must_use]
+    pub const fn rotated_clockwise(self) -> Delta {
+        let Delta { x_delta, y_delta } = self;
+        Delta {
+            x_delta: -y_delta,
+            y_delta: x_delta,
+        }
+    }
+
+    /// Rotate the delta by 90 degrees anticlockwise, so that a delta pointing north ends up pointing west.
+    #[must_use]
+    pub const fn rotated_anticlockwise(self) -> Delta {
+        let Delta { x_delta, y_delta } = self;
+        Delta {
+            x_delta: y_delta,
+            y_delta: -x_delta,
+        }
+    }
 }
 
-impl<const MAX_COORDINATE: u16> TryFrom<(Point<MAX_COORDINATE>, Point<MAX_COORDINATE>)> for Delta {
-    type Error = anyhow::Error;
+/// # Panics
+///
+/// Panics if either component overflows, even in release builds.
+impl Add for Delta {
+    type Output = Delta;
 
-    fn try_from(
-        value: (Point<MAX_COORDINATE>, Point<MAX_COORDINATE>),
-    ) -> Result<Self, Self::Error> {
-        let (point_a, point_b) = value;
+    fn add(self, rhs: Self) -> Self::Output {
+        Delta {
+            x_delta: self
+                .x_delta
+                .checked_add(rhs.x_delta)
+                .expect("Expected the sum of two deltas not to overflow"),
+            y_delta: self
+                .y_delta
+                .checked_add(rhs.y_delta)
+                .expect("Expected the sum of two deltas not to overflow"),
+        }
+    }
+}
 
-        Ok(Delta {
-            x_delta: point_a.x.difference_from(point_b.x)?,
-            y_delta: point_a.y.difference_from(point_b.y)?,
-        })
+impl Sub for Delta {
+    type Output = Delta;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl Neg for Delta {
+    type Output = Delta;
+
+    fn neg(self) -> Self::Output {
+        self.reversed()
+    }
+}
+
+/// # Panics
+///
+/// Panics if either component overflows, even in release builds.
+impl Mul<i32> for Delta {
+    type Output = Delta;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Delta {
+            x_delta: self
+                .x_delta
+                .checked_mul(rhs)
+                .expect("Expected a scaled delta not to overflow"),
+            y_delta: self
+                .y_delta
+                .checked_mul(rhs)
+                .expect("Expected a scaled delta not to overflow"),
+        }
+    }
+}
+
+impl From<EightPointCompass> for Delta {
+    fn from(value: EightPointCompass) -> Self {
+        match value {
+            EightPointCompass::North => Delta::new(0, -1),
+            EightPointCompass::East => Delta::new(1, 0),
+            EightPointCompass::South => Delta::new(0, 1),
+            EightPointCompass::West => Delta::new(-1, 0),
+            EightPointCompass::NorthEast => Delta::new(1, -1),
+            EightPointCompass::SouthEast => Delta::new(1, 1),
+            EightPointCompass::SouthWest => Delta::new(-1, 1),
+            EightPointCompass::NorthWest => Delta::new(-1, -1),
+        }
+    }
+}
+
+impl From<FourPointCompass> for Delta {
+    fn from(value: FourPointCompass) -> Self {
+        Delta::from(EightPointCompass::from(value))
+    }
+}
+
+/// Iterator returned by [`Point::line_to`].
+#[derive(Debug, Clone)]
+pub struct Line<const MAX_COORDINATE: u16> {
+    next: Option<Point<MAX_COORDINATE>>,
+    end: Point<MAX_COORDINATE>,
+    step: Delta,
+}
+
+impl<const MAX_COORDINATE: u16> Iterator for Line<MAX_COORDINATE> {
+    type Item = Point<MAX_COORDINATE>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.next?;
+        self.next = if point == self.end {
+            None
+        } else {
+            point.apply_delta(self.step)
+        };
+        Some(point)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EightPointCompass {
     North,
     East,
@@ -206,7 +641,7 @@ pub enum EightPointCompass {
     NorthWest,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FourPointCompass {
     North,
     South,
@@ -253,3 +688,127 @@ pub const ALL_FOUR_COMPASS_DIRECTIONS: &[FourPointCompass] = &[
     FourPointCompass::South,
     FourPointCompass::West,
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: usize, y: usize) -> Point<10> {
+        Point::try_from((x, y)).unwrap()
+    }
+
+    #[test]
+    fn delta_arithmetic() {
+        let delta = point(5, 2) - point(2, 4);
+        assert_eq!(delta, Delta::new(3, -2));
+        assert_eq!(-delta, Delta::new(-3, 2));
+        assert_eq!(delta * 2 - delta, delta);
+        assert_eq!(delta + delta.reversed(), Delta::ZERO);
+        assert_eq!(point(2, 4).apply_delta(delta), Some(point(5, 2)));
+    }
+
+    #[test]
+    fn rotations() {
+        let north = Delta::from(FourPointCompass::North);
+        assert_eq!(
+            north.rotated_clockwise(),
+            Delta::from(FourPointCompass::East)
+        );
+        assert_eq!(
+            north.rotated_anticlockwise(),
+            Delta::from(FourPointCompass::West)
+        );
+        for direction in ALL_FOUR_COMPASS_DIRECTIONS {
+            let mut rotated = *direction;
+            rotated.shift_90_degrees();
+            assert_eq!(
+                Delta::from(*direction).rotated_clockwise(),
+                Delta::from(rotated)
+            );
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(point(1, 1).neighbours4().count(), 2);
+        assert_eq!(point(10, 5).neighbours8().count(), 5);
+        assert_eq!(point(5, 5).neighbours8().count(), 8);
+
+        let mut grid = Grid::<10, char>::default();
+        grid.insert(point(1, 1), '.');
+        grid.insert(point(2, 1), '#');
+        grid.insert(point(1, 2), '.');
+        grid.insert(point(2, 2), '.');
+        assert_eq!(
+            grid.neighbours(point(1, 1)).collect::<Vec<_>>(),
+            [
+                (FourPointCompass::East, point(2, 1), &'#'),
+                (FourPointCompass::South, point(1, 2), &'.'),
+            ]
+        );
+        assert_eq!(grid.neighbours8(point(1, 1)).count(), 3);
+        assert_eq!(
+            grid.passable_neighbours(point(1, 1), |c| *c == '.')
+                .map(|(_, neighbour, _)| neighbour)
+                .collect::<Vec<_>>(),
+            [point(1, 2)]
+        );
+    }
+
+    fn render(grid: &Grid<10, char>) -> String {
+        grid.rows()
+            .map(|row| row.map(|(_, c)| *c).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn grid(s: &str) -> Grid<10, char> {
+        let mut grid = Grid::default();
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid.insert(point(x + 1, y + 1), c);
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn transforms() {
+        let original = grid("abc\ndef");
+        assert_eq!((original.width(), original.height()), (3, 2));
+        assert_eq!(render(&original.transposed()), "ad\nbe\ncf");
+        assert_eq!(render(&original.rotated_clockwise()), "da\neb\nfc");
+        assert_eq!(render(&original.rotated_anticlockwise()), "cf\nbe\nad");
+        assert_eq!(render(&original.flipped_horizontally()), "cba\nfed");
+        assert_eq!(render(&original.flipped_vertically()), "def\nabc");
+        assert_eq!(
+            render(&original.cropped(point(2, 1), point(3, 2))),
+            "bc\nef"
+        );
+        assert_eq!(
+            render(&original.tiled(2, 2).unwrap()),
+            "abcabc\ndefdef\nabcabc\ndefdef"
+        );
+        assert!(original.tiled(4, 1).is_err());
+        assert_eq!(render(&grid("#.").widened(|c| [*c, '+']).unwrap()), "#+.+");
+        assert_eq!(
+            original.column(2).map(|(_, c)| *c).collect::<String>(),
+            "be"
+        );
+        assert_eq!(original.columns().count(), 3);
+    }
+
+    #[test]
+    fn distances_and_lines() {
+        assert_eq!(point(1, 1).manhattan_distance(point(4, 3)), 5);
+        assert_eq!(point(1, 1).chebyshev_distance(point(4, 3)), 3);
+        assert_eq!(
+            point(1, 1).line_to(point(7, 5)).collect::<Vec<_>>(),
+            [point(1, 1), point(4, 3), point(7, 5)]
+        );
+        assert_eq!(
+            point(3, 3).line_to(point(3, 3)).collect::<Vec<_>>(),
+            [point(3, 3)]
+        );
+    }
+}
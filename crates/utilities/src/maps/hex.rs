@@ -0,0 +1,360 @@
+//! Hexagonal grids, using axial coordinates.
+//!
+//! A [`Hex`] is stored as the pair `(q, r)`; the third cube coordinate `s` is always `-q - r`.
+//! Hex grids come in two layouts, which differ only in how the six directions are named:
+//! pointy-topped hexes have neighbours to the east and west ([`PointyHexCompass`]),
+//! while flat-topped hexes have neighbours to the north and south ([`FlatHexCompass`]).
+//! Either compass can be used to move around the same [`HexGrid`], which is a
+//! [`SparseGrid`] of hexes.
+
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail};
+
+use crate::plane::{Coordinates, SparseGrid};
+
+/// The offsets to the six neighbours of a hex, in anticlockwise order starting from
+/// the east (pointy-topped) or south-east (flat-topped) neighbour.
+const NEIGHBOUR_OFFSETS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// The third cube coordinate, which is implied by the other two.
+    pub const fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Move one step in the given direction, or by any other offset.
+    #[must_use]
+    pub fn shift(self, direction: impl Into<Hex>) -> Self {
+        self + direction.into()
+    }
+
+    /// The number of steps between two hexes.
+    pub fn distance(self, other: Self) -> u64 {
+        let difference = self - other;
+        (difference.q.unsigned_abs() + difference.r.unsigned_abs() + difference.s().unsigned_abs())
+            / 2
+    }
+
+    /// Iterate over the six hexes next to this one, named by either compass.
+    pub fn neighbours<C: HexCompass>(self) -> impl Iterator<Item = (C, Hex)> {
+        C::ALL
+            .iter()
+            .map(move |&direction| (direction, self.shift(direction)))
+    }
+
+    /// Iterate over the hexes exactly `radius` steps away from this one.
+    ///
+    /// A ring of radius 0 is just the hex itself.
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Hex> {
+        let start = self + NEIGHBOUR_OFFSETS[4] * i64::from(radius);
+        let sides = if radius == 0 { 1 } else { 6 };
+        let side_length =
+            usize::try_from(radius.max(1)).expect("Expected the radius to fit into a usize");
+        (0..sides)
+            .flat_map(move |side| std::iter::repeat_n(NEIGHBOUR_OFFSETS[side], side_length))
+            .scan(start, |hex, offset| {
+                let current = *hex;
+                *hex = *hex + offset;
+                Some(current)
+            })
+    }
+
+    /// Iterate over every hex at most `radius` steps away from this one,
+    /// spiralling outwards one ring at a time.
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Hex> {
+        (0..=radius).flat_map(move |ring| self.ring(ring))
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Self::Output {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Hex::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Coordinates for Hex {
+    fn min_each(self, other: Self) -> Self {
+        Hex::new(self.q.min(other.q), self.r.min(other.r))
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        Hex::new(self.q.max(other.q), self.r.max(other.r))
+    }
+
+    fn shares_coordinate(self, other: Self) -> bool {
+        self.q == other.q || self.r == other.r
+    }
+
+    fn adjacent(self) -> impl Iterator<Item = Self> {
+        NEIGHBOUR_OFFSETS
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+}
+
+/// Either of the two ways of naming the six directions out of a hex.
+pub trait HexCompass: Copy + Into<Hex> + 'static {
+    /// All six directions, in clockwise order.
+    const ALL: &'static [Self];
+}
+
+impl HexCompass for PointyHexCompass {
+    const ALL: &'static [Self] = ALL_POINTY_HEX_DIRECTIONS;
+}
+
+impl HexCompass for FlatHexCompass {
+    const ALL: &'static [Self] = ALL_FLAT_HEX_DIRECTIONS;
+}
+
+/// The six directions out of a pointy-topped hex, written `e`, `se`, `sw`, `w`, `nw` and `ne`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexCompass {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl PointyHexCompass {
+    /// Parse a path written as directions with no separators between them, such as `esenee`.
+    pub fn parse_path(s: &str) -> anyhow::Result<Vec<Self>> {
+        let mut path = vec![];
+        let mut remaining = s.trim();
+        while !remaining.is_empty() {
+            let length = if remaining.starts_with(['n', 's']) {
+                2
+            } else {
+                1
+            };
+            let Some(direction) = remaining.get(..length) else {
+                bail!("Unexpected end of hex path {s:?}");
+            };
+            path.push(direction.parse()?);
+            remaining = &remaining[length..];
+        }
+        Ok(path)
+    }
+}
+
+impl FromStr for PointyHexCompass {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(Self::East),
+            "se" => Ok(Self::SouthEast),
+            "sw" => Ok(Self::SouthWest),
+            "w" => Ok(Self::West),
+            "nw" => Ok(Self::NorthWest),
+            "ne" => Ok(Self::NorthEast),
+            _ => Err(anyhow!("Unexpected pointy-topped hex direction {s:?}")),
+        }
+    }
+}
+
+impl From<PointyHexCompass> for Hex {
+    fn from(value: PointyHexCompass) -> Self {
+        match value {
+            PointyHexCompass::East => Hex::new(1, 0),
+            PointyHexCompass::NorthEast => Hex::new(1, -1),
+            PointyHexCompass::NorthWest => Hex::new(0, -1),
+            PointyHexCompass::West => Hex::new(-1, 0),
+            PointyHexCompass::SouthWest => Hex::new(-1, 1),
+            PointyHexCompass::SouthEast => Hex::new(0, 1),
+        }
+    }
+}
+
+pub const ALL_POINTY_HEX_DIRECTIONS: &[PointyHexCompass] = &[
+    PointyHexCompass::East,
+    PointyHexCompass::SouthEast,
+    PointyHexCompass::SouthWest,
+    PointyHexCompass::West,
+    PointyHexCompass::NorthWest,
+    PointyHexCompass::NorthEast,
+];
+
+/// The six directions out of a flat-topped hex, written `n`, `ne`, `se`, `s`, `sw` and `nw`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatHexCompass {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FromStr for FlatHexCompass {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(Self::North),
+            "ne" => Ok(Self::NorthEast),
+            "se" => Ok(Self::SouthEast),
+            "s" => Ok(Self::South),
+            "sw" => Ok(Self::SouthWest),
+            "nw" => Ok(Self::NorthWest),
+            _ => Err(anyhow!("Unexpected flat-topped hex direction {s:?}")),
+        }
+    }
+}
+
+impl From<FlatHexCompass> for Hex {
+    fn from(value: FlatHexCompass) -> Self {
+        match value {
+            FlatHexCompass::SouthEast => Hex::new(1, 0),
+            FlatHexCompass::NorthEast => Hex::new(1, -1),
+            FlatHexCompass::North => Hex::new(0, -1),
+            FlatHexCompass::NorthWest => Hex::new(-1, 0),
+            FlatHexCompass::SouthWest => Hex::new(-1, 1),
+            FlatHexCompass::South => Hex::new(0, 1),
+        }
+    }
+}
+
+pub const ALL_FLAT_HEX_DIRECTIONS: &[FlatHexCompass] = &[
+    FlatHexCompass::North,
+    FlatHexCompass::NorthEast,
+    FlatHexCompass::SouthEast,
+    FlatHexCompass::South,
+    FlatHexCompass::SouthWest,
+    FlatHexCompass::NorthWest,
+];
+
+/// A sparse map of hexes to values, which keeps track of its bounds in axial coordinates.
+pub type HexGrid<T> = SparseGrid<T, Hex>;
+
+impl<T> HexGrid<T> {
+    /// Iterate over the neighbours of `hex` that are present in the grid,
+    /// with their directions from `hex` named by either compass.
+    pub fn neighbours<C: HexCompass>(&self, hex: Hex) -> impl Iterator<Item = (C, Hex, &T)> + '_ {
+        hex.neighbours().filter_map(|(direction, neighbour)| {
+            self.get(&neighbour)
+                .map(|value| (direction, neighbour, value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn walk(directions: impl IntoIterator<Item = impl Into<Hex>>) -> Hex {
+        directions.into_iter().fold(Hex::ORIGIN, Hex::shift)
+    }
+
+    #[test]
+    fn flat_topped_distances() {
+        for (path, distance) in [
+            ("ne,ne,ne", 3),
+            ("ne,ne,sw,sw", 0),
+            ("ne,ne,s,s", 2),
+            ("se,sw,se,sw,sw", 3),
+        ] {
+            let directions: Vec<FlatHexCompass> = parse::separated(path, ",").unwrap();
+            assert_eq!(walk(directions).distance(Hex::ORIGIN), distance, "{path}");
+        }
+    }
+
+    #[test]
+    fn pointy_topped_paths() {
+        let path = PointyHexCompass::parse_path("nwwswee").unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(walk(path), Hex::ORIGIN);
+        assert_eq!(
+            walk(PointyHexCompass::parse_path("esew").unwrap()),
+            Hex::ORIGIN.shift(PointyHexCompass::SouthEast)
+        );
+        assert!(PointyHexCompass::parse_path("en").is_err());
+    }
+
+    #[test]
+    fn rings_and_spirals() {
+        let centre = Hex::new(2, -1);
+        assert_eq!(centre.ring(0).collect::<Vec<_>>(), [centre]);
+        for radius in 1..4 {
+            let ring: Vec<Hex> = centre.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring
+                .iter()
+                .all(|hex| hex.distance(centre) == u64::from(radius)));
+        }
+        assert_eq!(centre.spiral(2).count(), 19);
+
+        let grid: HexGrid<()> = centre.spiral(1).map(|hex| (hex, ())).collect();
+        assert_eq!(grid.neighbours::<PointyHexCompass>(centre).count(), 6);
+        let east = centre.shift(PointyHexCompass::East);
+        let directions: Vec<PointyHexCompass> = grid
+            .neighbours(east)
+            .map(|(direction, _, ())| direction)
+            .collect();
+        assert_eq!(
+            directions,
+            [
+                PointyHexCompass::SouthWest,
+                PointyHexCompass::West,
+                PointyHexCompass::NorthWest
+            ]
+        );
+        let (direction, hex, ()) = grid.neighbours::<FlatHexCompass>(east).next().unwrap();
+        assert_eq!(east.shift(direction), hex);
+        assert_eq!(
+            grid.bounds().map(|bounds| (bounds.min, bounds.max)),
+            Some((Hex::new(1, -2), Hex::new(3, 0)))
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! Three-dimensional counterparts of [`Point`](super::Point), [`Delta`](super::Delta),
+//! [`Grid`](super::Grid) and the compass types, for voxel puzzles.
+//!
+//! Voxel puzzles routinely use zero and negative coordinates, so unlike the bounded 2D types,
+//! coordinates here are signed and unbounded like those of [`plane`](crate::plane), and the grid
+//! is a [`SparseGrid`] of 3D points.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::plane::{Bounds, Coordinates, SparseGrid};
+
+/// The six directions that are perpendicular to the faces of a cube.
+///
+/// As in 2D, north is towards negative `y` and east is towards positive `x`;
+/// up is towards positive `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SixPointCompass {
+    North,
+    East,
+    South,
+    West,
+    Up,
+    Down,
+}
+
+pub const ALL_SIX_COMPASS_DIRECTIONS: &[SixPointCompass] = &[
+    SixPointCompass::North,
+    SixPointCompass::East,
+    SixPointCompass::South,
+    SixPointCompass::West,
+    SixPointCompass::Up,
+    SixPointCompass::Down,
+];
+
+/// One of the three axes of 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn shift(self, direction: SixPointCompass) -> Self {
+        self.apply_delta(Delta3::from(direction))
+    }
+
+    #[must_use]
+    pub fn apply_delta(self, delta: Delta3) -> Self {
+        self + delta
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Iterate over the six points that share a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = (SixPointCompass, Self)> {
+        ALL_SIX_COMPASS_DIRECTIONS
+            .iter()
+            .map(move |&direction| (direction, self.shift(direction)))
+    }
+
+    /// Iterate over the 26 points that share a face, an edge or a corner with this one,
+    /// each with the step that leads to it.
+    ///
+    /// There is no compass with 26 points, so the steps are deltas with each component in `-1..=1`.
+    pub fn neighbours26(self) -> impl Iterator<Item = (Delta3, Self)> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Delta3::new(x, y, z))))
+            .filter(|delta| *delta != Delta3::ZERO)
+            .map(move |delta| (delta, self + delta))
+    }
+
+    /// Rotate the point by 90 degrees about an axis through the origin.
+    ///
+    /// Rotations follow the right-hand rule: looking from the positive end of the axis
+    /// back towards the origin, the point turns anticlockwise.
+    #[must_use]
+    pub fn rotated_about(self, axis: Axis) -> Self {
+        let Point3 { x, y, z } = self;
+        match axis {
+            Axis::X => Point3::new(x, -z, y),
+            Axis::Y => Point3::new(z, y, -x),
+            Axis::Z => Point3::new(-y, x, z),
+        }
+    }
+
+    /// Iterate over the point under each of the 24 rotations of a cube about the origin.
+    ///
+    /// The rotations always come in the same order, so the `n`th item for two different
+    /// points is the result of applying the same rotation to each.
+    pub fn orientations(self) -> impl Iterator<Item = Self> {
+        let facings = [
+            self,
+            self.rotated_about(Axis::Z),
+            self.rotated_about(Axis::Z).rotated_about(Axis::Z),
+            self.rotated_about(Axis::Z)
+                .rotated_about(Axis::Z)
+                .rotated_about(Axis::Z),
+            self.rotated_about(Axis::Y),
+            self.rotated_about(Axis::Y)
+                .rotated_about(Axis::Y)
+                .rotated_about(Axis::Y),
+        ];
+        facings.into_iter().flat_map(|facing| {
+            std::iter::successors(Some(facing), |point| Some(point.rotated_about(Axis::X))).take(4)
+        })
+    }
+}
+
+impl Add<Delta3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Delta3) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub<Delta3> for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Delta3) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Delta3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Delta3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// The difference between two points in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Delta3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Delta3 {
+    pub const ZERO: Delta3 = Delta3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn reversed(self) -> Delta3 {
+        -self
+    }
+}
+
+impl Add for Delta3 {
+    type Output = Delta3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Delta3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Delta3 {
+    type Output = Delta3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Delta3 {
+    type Output = Delta3;
+
+    fn neg(self) -> Self::Output {
+        Delta3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Delta3 {
+    type Output = Delta3;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Delta3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl From<SixPointCompass> for Delta3 {
+    fn from(value: SixPointCompass) -> Self {
+        match value {
+            SixPointCompass::North => Delta3::new(0, -1, 0),
+            SixPointCompass::East => Delta3::new(1, 0, 0),
+            SixPointCompass::South => Delta3::new(0, 1, 0),
+            SixPointCompass::West => Delta3::new(-1, 0, 0),
+            SixPointCompass::Up => Delta3::new(0, 0, 1),
+            SixPointCompass::Down => Delta3::new(0, 0, -1),
+        }
+    }
+}
+
+impl Coordinates for Point3 {
+    fn min_each(self, other: Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    fn shares_coordinate(self, other: Self) -> bool {
+        self.x == other.x || self.y == other.y || self.z == other.z
+    }
+
+    fn adjacent(self) -> impl Iterator<Item = Self> {
+        self.neighbours6().map(|(_, point)| point)
+    }
+}
+
+/// An axis-aligned box of points, including its faces.
+pub type Cuboid = Bounds<Point3>;
+
+impl Cuboid {
+    /// The cuboid grown by `margin` in every direction.
+    #[must_use]
+    pub fn expanded(self, margin: i64) -> Self {
+        let margin = Delta3::new(margin, margin, margin);
+        Self {
+            min: self.min.apply_delta(-margin),
+            max: self.max.apply_delta(margin),
+        }
+    }
+}
+
+/// A sparse map of 3D points to values, which keeps track of its bounding cuboid.
+pub type Grid3<T> = SparseGrid<T, Point3>;
+
+impl<T> Grid3<T> {
+    /// Iterate over the face-adjacent neighbours of `point` that are present in the grid.
+    pub fn neighbours(
+        &self,
+        point: Point3,
+    ) -> impl Iterator<Item = (SixPointCompass, Point3, &T)> + '_ {
+        point.neighbours6().filter_map(|(direction, neighbour)| {
+            self.get(&neighbour)
+                .map(|value| (direction, neighbour, value))
+        })
+    }
+
+    /// The number of faces of the points in the grid that aren't shared with another point,
+    /// including the faces of any cavities enclosed inside.
+    pub fn surface_area(&self) -> usize {
+        self.keys()
+            .flat_map(|point| point.neighbours6())
+            .filter(|(_, neighbour)| !self.contains_key(neighbour))
+            .count()
+    }
+
+    /// Like [`Grid3::surface_area`], but only counting faces that can be reached from outside
+    /// by face-adjacent steps.
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some(bounds) = self.bounds() else {
+            return 0;
+        };
+        let outside = self.flood_fill(
+            bounds.min.shift(SixPointCompass::Down),
+            bounds.expanded(1),
+            |value| value.is_none(),
+        );
+        self.keys()
+            .flat_map(|point| point.neighbours6())
+            .filter(|(_, neighbour)| outside.contains(neighbour))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet;
+
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let point = Point3::new(1, 2, 3);
+        assert_eq!(point.rotated_about(Axis::Z), Point3::new(-2, 1, 3));
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let mut rotated = point;
+            for _ in 0..4 {
+                rotated = rotated.rotated_about(axis);
+            }
+            assert_eq!(rotated, point);
+        }
+        let orientations: FxHashSet<Point3> = point.orientations().collect();
+        assert_eq!(orientations.len(), 24);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        assert_eq!(Point3::ORIGIN.neighbours6().count(), 6);
+        let neighbours: FxHashSet<Point3> = Point3::ORIGIN
+            .neighbours26()
+            .map(|(delta, point)| {
+                assert_eq!(point - Point3::ORIGIN, delta);
+                point
+            })
+            .collect();
+        assert_eq!(neighbours.len(), 26);
+        assert!(neighbours
+            .iter()
+            .all(|point| point.manhattan_distance(Point3::ORIGIN) <= 3));
+    }
+
+    #[test]
+    fn surface_area() {
+        // A 3x3x3 cube with its centre hollowed out.
+        let grid: Grid3<()> = (0..3)
+            .flat_map(|x| (0..3).flat_map(move |y| (0..3).map(move |z| Point3::new(x, y, z))))
+            .filter(|point| *point != Point3::new(1, 1, 1))
+            .map(|point| (point, ()))
+            .collect();
+        assert_eq!(grid.surface_area(), 54 + 6);
+        assert_eq!(grid.exterior_surface_area(), 54);
+        assert_eq!(
+            grid.bounds(),
+            Some(Cuboid {
+                min: Point3::ORIGIN,
+                max: Point3::new(2, 2, 2)
+            })
+        );
+    }
+}
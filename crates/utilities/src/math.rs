@@ -0,0 +1,157 @@
+//! Number theory: greatest common divisors, modular arithmetic and the Chinese Remainder Theorem.
+
+/// The greatest common divisor of `a` and `b`. `gcd(0, 0)` is `0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it would overflow a `u64`.
+///
+/// `lcm(0, n)` is `0` for any `n`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The extended Euclidean algorithm.
+///
+/// Returns `(g, x, y)` such that `g` is the (non-negative) greatest common divisor of `a` and `b`
+/// and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut previous_remainder, mut remainder) = (a, b);
+    let (mut previous_a_coefficient, mut a_coefficient) = (1, 0);
+    let (mut previous_b_coefficient, mut b_coefficient) = (0, 1);
+
+    while remainder != 0 {
+        let quotient = previous_remainder / remainder;
+        (previous_remainder, remainder) = (remainder, previous_remainder - quotient * remainder);
+        (previous_a_coefficient, a_coefficient) = (
+            a_coefficient,
+            previous_a_coefficient - quotient * a_coefficient,
+        );
+        (previous_b_coefficient, b_coefficient) = (
+            b_coefficient,
+            previous_b_coefficient - quotient * b_coefficient,
+        );
+    }
+
+    if previous_remainder < 0 {
+        (
+            -previous_remainder,
+            -previous_a_coefficient,
+            -previous_b_coefficient,
+        )
+    } else {
+        (
+            previous_remainder,
+            previous_a_coefficient,
+            previous_b_coefficient,
+        )
+    }
+}
+
+/// The inverse of `a` modulo `modulus`: the value `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`.
+///
+/// Returns `None` if `a` and `modulus` are not coprime, in which case no inverse exists.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "Expected a positive modulus, got {modulus}");
+    let (divisor, coefficient, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (divisor == 1).then(|| coefficient.rem_euclid(modulus))
+}
+
+/// Compute `base.pow(exponent) % modulus` without overflowing, by repeated squaring.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "Expected a positive modulus, got {modulus}");
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    u64::try_from(result).expect("Expected the result to be smaller than the modulus")
+}
+
+/// Solve a system of congruences `x ≡ residue (mod modulus)` using the Chinese Remainder Theorem.
+///
+/// The moduli don't need to be pairwise coprime. Returns `(x, m)`, where `m` is the least common
+/// multiple of all the moduli and `x` in `0..m` is the smallest non-negative solution,
+/// or `None` if the congruences are inconsistent or `m` would overflow an `i64`.
+pub fn chinese_remainder(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let mut solution: i128 = 0;
+    let mut combined_modulus: i128 = 1;
+
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "Expected a positive modulus, got {modulus}");
+        let modulus = i128::from(modulus);
+        let residue = i128::from(residue).rem_euclid(modulus);
+
+        // We need `solution + combined_modulus * k ≡ residue (mod modulus)` for some `k`,
+        // which only has a solution if the gcd of the moduli divides the difference.
+        let (divisor, coefficient, _) = extended_gcd(
+            i64::try_from(combined_modulus).ok()?,
+            i64::try_from(modulus).ok()?,
+        );
+        let divisor = i128::from(divisor);
+        let difference = residue - solution;
+        if difference % divisor != 0 {
+            return None;
+        }
+
+        let step = modulus / divisor;
+        let k = (difference / divisor % step * i128::from(coefficient)).rem_euclid(step);
+        solution += combined_modulus * k;
+        combined_modulus *= step;
+        if combined_modulus > i128::from(i64::MAX) {
+            return None;
+        }
+        solution = solution.rem_euclid(combined_modulus);
+    }
+
+    Some((
+        i64::try_from(solution).ok()?,
+        i64::try_from(combined_modulus).ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn extended_euclid_and_inverse() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        assert_eq!(chinese_remainder([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(chinese_remainder([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(chinese_remainder([(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder([]), Some((0, 1)));
+    }
+}
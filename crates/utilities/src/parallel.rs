@@ -0,0 +1,74 @@
+//! Optional data parallelism for solvers, driven by one shared thread-count setting.
+//!
+//! The runner executes many solvers at once, so a solver that also spawned a thread per core
+//! would oversubscribe the machine. Instead, every helper here runs on one pool per process,
+//! with as many threads as [`threads`] allows; the runner controls that through the
+//! [`THREADS_VAR`] environment variable.
+
+use std::{num::NonZeroUsize, sync::OnceLock, thread};
+
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    ThreadPool, ThreadPoolBuilder,
+};
+
+/// The environment variable holding the number of threads each solver may use.
+pub const THREADS_VAR: &str = "AOC_THREADS";
+
+/// The number of threads a solver may use.
+///
+/// This is the value of [`THREADS_VAR`] if it is set to a positive number,
+/// or the number of available cores otherwise. A value of 1 disables parallelism.
+pub fn threads() -> usize {
+    std::env::var(THREADS_VAR)
+        .ok()
+        .and_then(|value| value.parse::<NonZeroUsize>().ok())
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+}
+
+/// The pool shared by every helper in this module, started the first time one of them is used.
+fn pool() -> &'static ThreadPool {
+    static POOL: OnceLock<ThreadPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        ThreadPoolBuilder::new()
+            .num_threads(threads())
+            .build()
+            .expect("Expected to be able to start the solver thread pool")
+    })
+}
+
+/// Apply `f` to every item on the shared pool.
+///
+/// The results are returned in the same order as the items.
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    pool().install(|| items.par_iter().map(f).collect())
+}
+
+/// Count the items for which `predicate` holds, checking them on the shared pool.
+pub fn count<T>(items: &[T], predicate: impl Fn(&T) -> bool + Sync + Send) -> usize
+where
+    T: Sync,
+{
+    pool().install(|| items.par_iter().filter(|item| predicate(item)).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preserves_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], |n| *n).is_empty());
+        assert_eq!(count(&items, |n| n % 3 == 0), 334);
+        assert!(threads() >= 1);
+        assert_eq!(pool().current_num_threads(), threads());
+    }
+}
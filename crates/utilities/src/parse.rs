@@ -0,0 +1,173 @@
+//! Helpers for the shapes of input that come up again and again in the puzzles.
+//!
+//! Every helper returns an [`anyhow::Error`] saying what was expected and what was found,
+//! so day crates can just use `?` rather than writing their own error messages.
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+
+/// Parse a single item, adding the offending text to the error if it fails.
+pub fn item<T>(s: &str) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    s.parse::<T>()
+        .map_err(Into::into)
+        .with_context(|| format!("Failed to parse {s:?}"))
+}
+
+/// Iterate over the blocks of an input that are separated by blank lines.
+///
+/// Trailing newlines at the end of the input are ignored.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches('\n').split("\n\n")
+}
+
+/// Split an input into exactly `N` blocks separated by blank lines.
+pub fn sections<const N: usize>(input: &str) -> anyhow::Result<[&str; N]> {
+    let sections: Vec<&str> = blocks(input).collect();
+    <[&str; N]>::try_from(sections).map_err(|sections| {
+        anyhow!(
+            "Expected the input to have {N} sections separated by blank lines, found {}",
+            sections.len()
+        )
+    })
+}
+
+/// Parse every line of the input, reporting the (one-based) line number on failure.
+pub fn lines<T>(input: &str) -> anyhow::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| item(line).with_context(|| format!("On line {}", index + 1)))
+        .collect()
+}
+
+/// Parse a list of values separated by `delimiter`, such as `1,2,3`.
+pub fn separated<T>(s: &str, delimiter: &str) -> anyhow::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    s.split(delimiter).map(item).collect()
+}
+
+/// Parse a list of values separated by any amount of whitespace.
+pub fn whitespace_separated<T>(s: &str) -> anyhow::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    s.split_whitespace().map(item).collect()
+}
+
+/// Parse a line that must contain exactly `N` whitespace-separated columns.
+pub fn columns<T, const N: usize>(line: &str) -> anyhow::Result<[T; N]>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    let values: Vec<T> = whitespace_separated(line)?;
+    <[T; N]>::try_from(values).map_err(|values| {
+        anyhow!(
+            "Expected {N} whitespace-separated columns, found {} in {line:?}",
+            values.len()
+        )
+    })
+}
+
+/// Split `s` around the one and only occurrence of `separator`.
+pub fn pair<'a>(s: &'a str, separator: &str) -> anyhow::Result<(&'a str, &'a str)> {
+    let (left, right) = s
+        .split_once(separator)
+        .with_context(|| format!("Expected {separator:?} to appear in {s:?}"))?;
+    if right.contains(separator) {
+        bail!("Expected {separator:?} to appear only once in {s:?}");
+    }
+    Ok((left, right))
+}
+
+/// Parse a line of the form `key: value value value`, with at least one value.
+pub fn key_values<K, V>(line: &str) -> anyhow::Result<(K, Vec<V>)>
+where
+    K: FromStr,
+    K::Err: Into<anyhow::Error>,
+    V: FromStr,
+    V::Err: Into<anyhow::Error>,
+{
+    let (key, values) = pair(line, ": ")?;
+    if values.contains(':') {
+        bail!("Expected only one ':' in {line:?}");
+    }
+    let values = whitespace_separated(values)?;
+    if values.is_empty() {
+        bail!("Expected at least one value after the ':' in {line:?}");
+    }
+    Ok((item(key)?, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_and_sections() {
+        assert_eq!(blocks("").collect::<Vec<_>>(), [""]);
+        assert_eq!(blocks("a\nb\n\nc\n").collect::<Vec<_>>(), ["a\nb", "c"]);
+        assert_eq!(blocks("a\n\nb\n\n").collect::<Vec<_>>(), ["a", "b"]);
+
+        assert_eq!(sections::<2>("a\n\nb\n").unwrap(), ["a", "b"]);
+        assert!(sections::<2>("a\nb\n").is_err());
+        assert!(sections::<2>("a\n\nb\n\nc").is_err());
+    }
+
+    #[test]
+    fn lines_and_lists() {
+        assert_eq!(lines::<u8>("").unwrap(), []);
+        assert_eq!(lines::<u8>("1\n2\n").unwrap(), [1, 2]);
+        let error = lines::<u8>("1\nx\n").unwrap_err();
+        assert_eq!(error.to_string(), "On line 2");
+
+        assert_eq!(separated::<u8>("1,2,3", ",").unwrap(), [1, 2, 3]);
+        assert!(separated::<u8>("", ",").is_err());
+        assert!(separated::<u8>("1,,3", ",").is_err());
+
+        assert_eq!(whitespace_separated::<u8>(" 1  2\t3 ").unwrap(), [1, 2, 3]);
+        assert_eq!(whitespace_separated::<u8>("").unwrap(), []);
+    }
+
+    #[test]
+    fn columns_must_match() {
+        assert_eq!(columns::<u32, 2>("3   4").unwrap(), [3, 4]);
+        assert!(columns::<u32, 2>("3").is_err());
+        assert!(columns::<u32, 2>("3 4 5").is_err());
+        assert!(columns::<u32, 2>("").is_err());
+    }
+
+    #[test]
+    fn pairs() {
+        assert_eq!(pair("47|53", "|").unwrap(), ("47", "53"));
+        assert_eq!(pair("|", "|").unwrap(), ("", ""));
+        assert!(pair("47 53", "|").is_err());
+        assert!(pair("47|53|61", "|").is_err());
+    }
+
+    #[test]
+    fn key_value_lines() {
+        assert_eq!(
+            key_values::<u64, u64>("190: 10 19").unwrap(),
+            (190, vec![10, 19])
+        );
+        assert!(key_values::<u64, u64>("190 10 19").is_err());
+        assert!(key_values::<u64, u64>("7: ").is_err());
+        assert!(key_values::<u64, u64>("7:").is_err());
+        assert!(key_values::<u64, u64>("7: 1:2").is_err());
+        assert!(key_values::<u64, u64>("7: 1 x").is_err());
+    }
+}
@@ -0,0 +1,237 @@
+//! Finding small 2D templates in a [`Grid`], optionally under every rotation and reflection.
+
+use std::str::FromStr;
+
+use crate::maps::{Delta, Grid, Point};
+
+/// The character that matches any value (or no value at all) when parsing a [`Pattern`].
+pub const WILDCARD: char = '?';
+
+/// One of the eight ways of rotating and reflecting a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    /// Whether the pattern is mirrored from east to west before it is rotated.
+    pub reflected: bool,
+    /// How many times the pattern is rotated by 90 degrees clockwise, from 0 to 3.
+    pub quarter_turns: u8,
+}
+
+impl Orientation {
+    pub const IDENTITY: Orientation = Orientation {
+        reflected: false,
+        quarter_turns: 0,
+    };
+
+    pub const ALL: [Orientation; 8] = [
+        Self::new(false, 0),
+        Self::new(false, 1),
+        Self::new(false, 2),
+        Self::new(false, 3),
+        Self::new(true, 0),
+        Self::new(true, 1),
+        Self::new(true, 2),
+        Self::new(true, 3),
+    ];
+
+    const fn new(reflected: bool, quarter_turns: u8) -> Self {
+        Self {
+            reflected,
+            quarter_turns,
+        }
+    }
+
+    fn apply(self, delta: Delta) -> Delta {
+        let mut delta = if self.reflected {
+            Delta::new(-delta.x_delta(), delta.y_delta())
+        } else {
+            delta
+        };
+        for _ in 0..self.quarter_turns {
+            delta = delta.rotated_clockwise();
+        }
+        delta
+    }
+}
+
+/// A match of a [`Pattern`] in a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<const MAX_COORDINATE: u16> {
+    /// The north-west corner of the bounding box of the matched cells.
+    pub anchor: Point<MAX_COORDINATE>,
+    pub orientation: Orientation,
+}
+
+/// A small 2D template of values, with holes that match anything.
+///
+/// Patterns are written out like the grids they match, using [`WILDCARD`] for the holes:
+///
+/// ```text
+/// M?S
+/// ?A?
+/// M?S
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern<T> {
+    /// Offsets from the north-west corner of the pattern's bounding box,
+    /// sorted from north to south and then from west to east.
+    cells: Vec<(Delta, T)>,
+}
+
+impl<T: Clone> Pattern<T> {
+    /// Build a pattern from the values at each offset from the anchor.
+    pub fn new(cells: impl IntoIterator<Item = (Delta, T)>) -> Self {
+        let mut cells: Vec<(Delta, T)> = cells.into_iter().collect();
+        let min_x = cells.iter().map(|(delta, _)| delta.x_delta()).min();
+        let min_y = cells.iter().map(|(delta, _)| delta.y_delta()).min();
+        if let (Some(min_x), Some(min_y)) = (min_x, min_y) {
+            for (delta, _) in &mut cells {
+                *delta = *delta - Delta::new(min_x, min_y);
+            }
+        }
+        cells.sort_by_key(|(delta, _)| (delta.y_delta(), delta.x_delta()));
+        Self { cells }
+    }
+
+    /// The same pattern rotated and reflected into the given orientation.
+    #[must_use]
+    pub fn oriented(&self, orientation: Orientation) -> Self {
+        Self::new(
+            self.cells
+                .iter()
+                .map(|(delta, value)| (orientation.apply(*delta), value.clone())),
+        )
+    }
+}
+
+impl<T: Clone + PartialEq> Pattern<T> {
+    /// Whether the pattern matches with its north-west corner at `anchor`.
+    pub fn matches_at<const MAX_COORDINATE: u16>(
+        &self,
+        grid: &Grid<MAX_COORDINATE, T>,
+        anchor: Point<MAX_COORDINATE>,
+    ) -> bool {
+        self.cells.iter().all(|(delta, expected)| {
+            anchor
+                .apply_delta(*delta)
+                .and_then(|point| grid.get(&point))
+                .is_some_and(|value| value == expected)
+        })
+    }
+
+    /// Find every match of the pattern in the grid, without rotating or reflecting it.
+    pub fn find<'a, const MAX_COORDINATE: u16>(
+        &'a self,
+        grid: &'a Grid<MAX_COORDINATE, T>,
+    ) -> impl Iterator<Item = Match<MAX_COORDINATE>> + 'a {
+        Self::find_oriented(grid, Orientation::IDENTITY, self.clone())
+    }
+
+    /// Find every match of the pattern in the grid under any rotation or reflection.
+    ///
+    /// Orientations that leave a symmetric pattern unchanged are skipped,
+    /// so each occurrence in the grid is only reported once.
+    pub fn find_in_all_orientations<'a, const MAX_COORDINATE: u16>(
+        &'a self,
+        grid: &'a Grid<MAX_COORDINATE, T>,
+    ) -> impl Iterator<Item = Match<MAX_COORDINATE>> + 'a {
+        self.distinct_orientations()
+            .into_iter()
+            .flat_map(move |(orientation, pattern)| Self::find_oriented(grid, orientation, pattern))
+    }
+
+    fn distinct_orientations(&self) -> Vec<(Orientation, Pattern<T>)> {
+        let mut distinct: Vec<(Orientation, Pattern<T>)> = vec![];
+        for orientation in Orientation::ALL {
+            let pattern = self.oriented(orientation);
+            if distinct.iter().all(|(_, seen)| *seen != pattern) {
+                distinct.push((orientation, pattern));
+            }
+        }
+        distinct
+    }
+
+    fn find_oriented<'a, const MAX_COORDINATE: u16>(
+        grid: &'a Grid<MAX_COORDINATE, T>,
+        orientation: Orientation,
+        pattern: Pattern<T>,
+    ) -> impl Iterator<Item = Match<MAX_COORDINATE>> + 'a
+    where
+        T: 'a,
+    {
+        grid.keys()
+            .copied()
+            .filter(move |anchor| pattern.matches_at(grid, *anchor))
+            .map(move |anchor| Match {
+                anchor,
+                orientation,
+            })
+    }
+}
+
+impl<T> FromStr for Pattern<T>
+where
+    T: Clone + TryFrom<char>,
+    T::Error: Into<anyhow::Error>,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = vec![];
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == WILDCARD {
+                    continue;
+                }
+                let delta = Delta::new(i32::try_from(x)?, i32::try_from(y)?);
+                cells.push((delta, T::try_from(c).map_err(Into::into)?));
+            }
+        }
+        Ok(Self::new(cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientations() {
+        let pattern: Pattern<char> = "ab\nc?".parse().unwrap();
+        let rotated: Pattern<char> = "ca\n?b".parse().unwrap();
+        let reflected: Pattern<char> = "ba\n?c".parse().unwrap();
+        let orientation = Orientation::new;
+        assert_eq!(pattern.oriented(orientation(false, 1)), rotated);
+        assert_eq!(pattern.oriented(orientation(true, 0)), reflected);
+        assert_eq!(
+            pattern
+                .oriented(orientation(true, 2))
+                .oriented(orientation(true, 2)),
+            pattern
+        );
+        assert_eq!(pattern.distinct_orientations().len(), 8);
+
+        let symmetric: Pattern<char> = "a?a\n?b?\na?a".parse().unwrap();
+        assert_eq!(symmetric.distinct_orientations().len(), 1);
+    }
+
+    #[test]
+    fn find() {
+        let grid: Grid<10, char> = "\
+xab
+xcx
+bax"
+        .parse()
+        .unwrap();
+        let pattern: Pattern<char> = "ab".parse().unwrap();
+        let point = |x, y| Point::try_from((x, y)).unwrap();
+
+        assert_eq!(
+            pattern.find(&grid).collect::<Vec<_>>(),
+            [Match {
+                anchor: point(2, 1),
+                orientation: Orientation::IDENTITY
+            }]
+        );
+        assert_eq!(pattern.find_in_all_orientations(&grid).count(), 2);
+    }
+}
@@ -0,0 +1,481 @@
+//! An unbounded plane of signed coordinates, for puzzles where points can wander anywhere.
+//!
+//! Unlike [`maps::Point`], a [`Point`] here can be negative and can never fall off the edge.
+//! Points convert losslessly to and from bounded points: a bounded point keeps its
+//! (one-based) coordinates, and a plane point converts back only if it lies within the grid.
+//!
+//! [`SparseGrid`] is also the storage for the other unbounded grids, such as
+//! [`Grid3`](crate::maps::Grid3), through the [`Coordinates`] trait.
+
+use std::{
+    collections::VecDeque,
+    hash::Hash,
+    ops::{Add, Deref, Mul, Neg, Sub},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::maps::{
+    self, EightPointCompass, FourPointCompass, ALL_EIGHT_COMPASS_DIRECTIONS,
+    ALL_FOUR_COMPASS_DIRECTIONS,
+};
+
+/// A point on the unbounded plane. As with [`maps::Point`], `y` increases southwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    #[must_use]
+    pub fn shift(self, direction: impl Into<EightPointCompass>) -> Self {
+        self + Delta::from(direction.into())
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Iterate over the points directly north, east, south and west of this one.
+    pub fn neighbours4(self) -> impl Iterator<Item = (FourPointCompass, Point)> {
+        ALL_FOUR_COMPASS_DIRECTIONS
+            .iter()
+            .map(move |&direction| (direction, self.shift(direction)))
+    }
+
+    /// Iterate over all eight points surrounding this one, including diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = (EightPointCompass, Point)> {
+        ALL_EIGHT_COMPASS_DIRECTIONS
+            .iter()
+            .map(move |&direction| (direction, self.shift(direction)))
+    }
+}
+
+impl Add<Delta> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Delta) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub<Delta> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Delta) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Sub for Point {
+    type Output = Delta;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Delta::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The difference between two points on the unbounded plane.
+///
+/// This is the signed counterpart of [`maps::Delta`], with the same operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Delta {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Delta {
+    pub const ZERO: Delta = Delta::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Rotate the delta by 90 degrees clockwise, so that a delta pointing north ends up pointing east.
+    #[must_use]
+    pub const fn rotated_clockwise(self) -> Delta {
+        Delta::new(-self.y, self.x)
+    }
+
+    /// Rotate the delta by 90 degrees anticlockwise, so that a delta pointing north ends up pointing west.
+    #[must_use]
+    pub const fn rotated_anticlockwise(self) -> Delta {
+        Delta::new(self.y, -self.x)
+    }
+}
+
+impl Add for Delta {
+    type Output = Delta;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Delta::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Delta {
+    type Output = Delta;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Delta {
+    type Output = Delta;
+
+    fn neg(self) -> Self::Output {
+        Delta::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Delta {
+    type Output = Delta;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Delta::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl From<maps::Delta> for Delta {
+    fn from(value: maps::Delta) -> Self {
+        Delta::new(i64::from(value.x_delta()), i64::from(value.y_delta()))
+    }
+}
+
+impl From<EightPointCompass> for Delta {
+    fn from(value: EightPointCompass) -> Self {
+        Delta::from(maps::Delta::from(value))
+    }
+}
+
+impl From<FourPointCompass> for Delta {
+    fn from(value: FourPointCompass) -> Self {
+        Delta::from(maps::Delta::from(value))
+    }
+}
+
+impl<const MAX_COORDINATE: u16> From<maps::Point<MAX_COORDINATE>> for Point {
+    fn from(value: maps::Point<MAX_COORDINATE>) -> Self {
+        let coordinate =
+            |c: usize| i64::try_from(c).expect("Expected a bounded coordinate to fit in an i64");
+        Point::new(coordinate(value.x()), coordinate(value.y()))
+    }
+}
+
+impl<const MAX_COORDINATE: u16> TryFrom<Point> for maps::Point<MAX_COORDINATE> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        maps::Point::try_from((usize::try_from(value.x)?, usize::try_from(value.y)?))
+    }
+}
+
+/// A kind of point that a [`SparseGrid`] can hold.
+pub trait Coordinates: Copy + Eq + Hash {
+    /// The point whose coordinates are the smaller of each pair of coordinates.
+    #[must_use]
+    fn min_each(self, other: Self) -> Self;
+
+    /// The point whose coordinates are the larger of each pair of coordinates.
+    #[must_use]
+    fn max_each(self, other: Self) -> Self;
+
+    /// Whether any coordinate of this point equals the same coordinate of `other`.
+    fn shares_coordinate(self, other: Self) -> bool;
+
+    /// The points one step away from this one, which searches move between.
+    fn adjacent(self) -> impl Iterator<Item = Self>;
+}
+
+impl Coordinates for Point {
+    fn min_each(self, other: Self) -> Self {
+        Point::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn max_each(self, other: Self) -> Self {
+        Point::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    fn shares_coordinate(self, other: Self) -> bool {
+        self.x == other.x || self.y == other.y
+    }
+
+    fn adjacent(self) -> impl Iterator<Item = Self> {
+        self.neighbours4().map(|(_, point)| point)
+    }
+}
+
+/// The smallest box containing a set of points, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds<P = Point> {
+    /// The corner with the smallest coordinates (the north-west corner, on the plane).
+    pub min: P,
+    /// The corner with the largest coordinates (the south-east corner, on the plane).
+    pub max: P,
+}
+
+impl<P: Coordinates> Bounds<P> {
+    fn of(point: P) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    #[must_use]
+    fn including(self, point: P) -> Self {
+        Self {
+            min: self.min.min_each(point),
+            max: self.max.max_each(point),
+        }
+    }
+
+    pub fn contains(self, point: P) -> bool {
+        self.min.max_each(point) == point && self.max.min_each(point) == point
+    }
+
+    /// Whether `point` lies on one of the faces of the box, where removing it could shrink the box.
+    fn on_edge(self, point: P) -> bool {
+        point.shares_coordinate(self.min) || point.shares_coordinate(self.max)
+    }
+}
+
+impl Bounds {
+    pub fn width(self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    pub fn height(self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    /// Iterate over every point in the rectangle, row by row from north to south.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (self.min.y..=self.max.y)
+            .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+/// A sparse map from unbounded points to values,
+/// which keeps track of the bounding box of the points it contains.
+///
+/// It derefs to the underlying map for reading, but all changes must go through
+/// [`SparseGrid::insert`] and [`SparseGrid::remove`] so that the bounds stay correct.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T, P = Point> {
+    cells: FxHashMap<P, T>,
+    bounds: Option<Bounds<P>>,
+}
+
+impl<T, P> Default for SparseGrid<T, P> {
+    fn default() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T, P: Coordinates> SparseGrid<T, P> {
+    /// The bounding box of every point in the grid, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds<P>> {
+        self.bounds
+    }
+
+    pub fn insert(&mut self, point: P, value: T) -> Option<T> {
+        self.bounds = Some(
+            self.bounds
+                .map_or(Bounds::of(point), |bounds| bounds.including(point)),
+        );
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: P) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(point)) {
+            self.bounds = self.cells.keys().fold(None, |bounds, &point| {
+                Some(bounds.map_or(Bounds::of(point), |bounds| bounds.including(point)))
+            });
+        }
+        Some(removed)
+    }
+
+    pub fn get_mut(&mut self, point: P) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Every point reachable from `start` by steps between adjacent points without leaving `within`,
+    /// where `is_passable` is given the value at each point (or `None` if it is empty).
+    pub fn flood_fill(
+        &self,
+        start: P,
+        within: Bounds<P>,
+        is_passable: impl Fn(Option<&T>) -> bool,
+    ) -> FxHashSet<P> {
+        let mut reached = FxHashSet::default();
+        if !within.contains(start) || !is_passable(self.get(&start)) {
+            return reached;
+        }
+        reached.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(point) = queue.pop_front() {
+            for neighbour in point.adjacent() {
+                if within.contains(neighbour)
+                    && is_passable(self.get(&neighbour))
+                    && reached.insert(neighbour)
+                {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        reached
+    }
+}
+
+impl<T, P> Deref for SparseGrid<T, P> {
+    type Target = FxHashMap<P, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cells
+    }
+}
+
+impl<T, P: Coordinates> FromIterator<(P, T)> for SparseGrid<T, P> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = Self::default();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, P: Coordinates> Extend<(P, T)> for SparseGrid<T, P> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<const MAX_COORDINATE: u16, T> From<maps::Grid<MAX_COORDINATE, T>> for SparseGrid<T> {
+    fn from(value: maps::Grid<MAX_COORDINATE, T>) -> Self {
+        value
+            .into_iter()
+            .map(|(point, value)| (Point::from(point), value))
+            .collect()
+    }
+}
+
+impl<const MAX_COORDINATE: u16, T> TryFrom<SparseGrid<T>> for maps::Grid<MAX_COORDINATE, T> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: SparseGrid<T>) -> Result<Self, Self::Error> {
+        let mut grid = maps::Grid::default();
+        for (point, value) in value.cells {
+            grid.insert(maps::Point::try_from(point)?, value);
+        }
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let point = Point::new(-3, 4);
+        let delta = Delta::new(5, -5);
+        assert_eq!(point + delta, Point::new(2, -1));
+        assert_eq!(point + delta - point, delta);
+        assert_eq!(point - delta * 2, Point::new(-13, 14));
+        assert_eq!(-delta, Delta::new(-5, 5));
+        assert_eq!(
+            Delta::from(FourPointCompass::North).rotated_clockwise(),
+            Delta::from(FourPointCompass::East)
+        );
+        assert_eq!(point.shift(EightPointCompass::NorthWest), Point::new(-4, 3));
+        assert_eq!(point.manhattan_distance(Point::ORIGIN), 7);
+        assert_eq!(point.chebyshev_distance(Point::ORIGIN), 4);
+        assert_eq!(
+            Point::ORIGIN.neighbours4().collect::<Vec<_>>(),
+            [
+                (FourPointCompass::North, Point::new(0, -1)),
+                (FourPointCompass::East, Point::new(1, 0)),
+                (FourPointCompass::South, Point::new(0, 1)),
+                (FourPointCompass::West, Point::new(-1, 0)),
+            ]
+        );
+        assert_eq!(Point::ORIGIN.neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn conversions() {
+        let bounded = maps::Point::<10>::try_from((3, 7)).unwrap();
+        assert_eq!(Point::from(bounded), Point::new(3, 7));
+        assert_eq!(
+            maps::Point::<10>::try_from(Point::from(bounded)).unwrap(),
+            bounded
+        );
+        assert!(maps::Point::<10>::try_from(Point::new(0, 1)).is_err());
+        assert!(maps::Point::<10>::try_from(Point::new(11, 1)).is_err());
+        assert!(maps::Point::<10>::try_from(Point::new(-1, 1)).is_err());
+    }
+
+    #[test]
+    fn bounds_tracking() {
+        let mut grid: SparseGrid<char> = [(Point::new(-2, 5), 'a'), (Point::new(3, -1), 'b')]
+            .into_iter()
+            .collect();
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(bounds.min, Point::new(-2, -1));
+        assert_eq!(bounds.max, Point::new(3, 5));
+        assert_eq!((bounds.width(), bounds.height()), (6, 7));
+        assert_eq!(bounds.points().count(), 42);
+
+        grid.insert(Point::new(0, 0), 'c');
+        assert_eq!(grid.remove(Point::new(-2, 5)), Some('a'));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point::new(0, -1),
+                max: Point::new(3, 0)
+            })
+        );
+        grid.remove(Point::new(0, 0));
+        grid.remove(Point::new(3, -1));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn flood_fill() {
+        // A ring of walls around (1, 1).
+        let grid: SparseGrid<()> = Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(2, 2),
+        }
+        .points()
+        .filter(|point| *point != Point::new(1, 1))
+        .map(|point| (point, ()))
+        .collect();
+        let empty = |value: Option<&()>| value.is_none();
+        let within = Bounds {
+            min: Point::new(-1, -1),
+            max: Point::new(3, 3),
+        };
+        assert_eq!(grid.flood_fill(Point::new(-1, -1), within, empty).len(), 16);
+        assert_eq!(grid.flood_fill(Point::new(1, 1), within, empty).len(), 1);
+        assert!(grid.flood_fill(Point::new(4, 4), within, empty).is_empty());
+    }
+}
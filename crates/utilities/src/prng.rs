@@ -0,0 +1,67 @@
+//! Pseudorandom sequences defined by puzzles.
+
+/// The sequence of secret numbers a monkey-market buyer generates, starting from their
+/// initial secret number.
+///
+/// The initial secret number itself is not yielded: the first item is the result of evolving it once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretNumbers {
+    secret: u64,
+}
+
+impl SecretNumbers {
+    pub const fn new(initial_secret: u64) -> Self {
+        Self {
+            secret: initial_secret,
+        }
+    }
+
+    const fn mix(secret: u64, value: u64) -> u64 {
+        secret ^ value
+    }
+
+    const fn prune(secret: u64) -> u64 {
+        secret % 16_777_216
+    }
+
+    /// The secret number that follows `secret`.
+    pub const fn evolve(secret: u64) -> u64 {
+        // Pruning keeps only the low 24 bits, which the high bits never affect,
+        // so pruning first keeps the multiplications from overflowing.
+        let secret = Self::prune(secret);
+        let secret = Self::prune(Self::mix(secret, secret * 64));
+        let secret = Self::prune(Self::mix(secret, secret / 32));
+        Self::prune(Self::mix(secret, secret * 2048))
+    }
+}
+
+impl Iterator for SecretNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.secret = Self::evolve(self.secret);
+        Some(self.secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_numbers() {
+        let first_ten: Vec<u64> = SecretNumbers::new(123).take(10).collect();
+        assert_eq!(
+            first_ten,
+            [
+                15_887_950, 16_495_136, 527_345, 704_524, 1_553_684, 12_683_156, 11_100_544,
+                12_249_484, 7_753_432, 5_908_254
+            ]
+        );
+        assert_eq!(SecretNumbers::evolve(123), 15_887_950);
+        assert_eq!(
+            SecretNumbers::evolve(u64::MAX),
+            SecretNumbers::evolve(16_777_215)
+        );
+    }
+}
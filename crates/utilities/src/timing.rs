@@ -0,0 +1,115 @@
+//! Timing how long a solver spends parsing its input and solving the puzzle.
+//!
+//! The runner can only time a solver's whole process from the outside, so solvers time
+//! their own phases and report them on stderr as one line of [`Timings`], which the runner
+//! picks back out. A solver calls [`start`] before loading its input, [`Parsing::parsed`] once
+//! the input is parsed, and [`Solving::solved`] once it has the answer.
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// What the line of [`Timings`] on a solver's stderr starts with.
+pub const PREFIX: &str = "timing:";
+
+/// Start timing the parsing phase, which should include loading the input.
+pub fn start() -> Parsing {
+    Parsing {
+        start: Instant::now(),
+    }
+}
+
+/// A timer for a solver that is still parsing its input.
+#[derive(Debug)]
+pub struct Parsing {
+    start: Instant,
+}
+
+impl Parsing {
+    /// Finish parsing, and start timing the solving phase.
+    pub fn parsed(self) -> Solving {
+        let now = Instant::now();
+        Solving {
+            parse_time: now - self.start,
+            start: now,
+        }
+    }
+}
+
+/// A timer for a solver that has parsed its input and is working out the answer.
+#[derive(Debug)]
+pub struct Solving {
+    parse_time: Duration,
+    start: Instant,
+}
+
+impl Solving {
+    /// Finish solving, and report how long both phases took on stderr.
+    pub fn solved(self) {
+        let timings = Timings {
+            parse_time: self.parse_time,
+            solve_time: self.start.elapsed(),
+        };
+        eprintln!("{timings}");
+    }
+}
+
+/// How long a solver spent in each phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Timings {
+    /// Read timings back from a line written by [`Solving::solved`],
+    /// or return `None` if the line is anything else.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let fields = line.strip_prefix(PREFIX)?;
+        let mut parse_time = None;
+        let mut solve_time = None;
+        for field in fields.split_ascii_whitespace() {
+            let (name, micros) = field.split_once('=')?;
+            let time = Duration::from_micros(micros.parse().ok()?);
+            match name {
+                "parse_us" => parse_time = Some(time),
+                "solve_us" => solve_time = Some(time),
+                _ => return None,
+            }
+        }
+        Some(Self {
+            parse_time: parse_time?,
+            solve_time: solve_time?,
+        })
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{PREFIX} parse_us={} solve_us={}",
+            self.parse_time.as_micros(),
+            self.solve_time.as_micros()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let timings = Timings {
+            parse_time: Duration::from_micros(1500),
+            solve_time: Duration::from_micros(27),
+        };
+        assert_eq!(timings.to_string(), "timing: parse_us=1500 solve_us=27");
+        assert_eq!(Timings::from_line(&timings.to_string()), Some(timings));
+        assert_eq!(Timings::from_line("timing: parse_us=1500"), None);
+        assert_eq!(Timings::from_line("timing: parse_us=x solve_us=1"), None);
+        assert_eq!(Timings::from_line("Best sequence: -2,1,-1,3"), None);
+    }
+}
@@ -0,0 +1,177 @@
+use std::str::CharIndices;
+
+use rustc_hash::FxHashMap;
+
+/// A prefix tree over a set of strings.
+///
+/// The main use is [`Trie::prefixes_of`], which finds every stored string that `text` starts with
+/// in a single pass over `text`, rather than checking each stored string in turn.
+///
+/// The empty string is never stored: it is a prefix of everything, so a caller that
+/// repeatedly strips prefixes off a string would never make progress.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: FxHashMap<char, usize>,
+    terminal: bool,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct strings stored in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add a string to the trie, returning `false` if it was already present or is empty.
+    pub fn insert(&mut self, word: &str) -> bool {
+        if word.is_empty() {
+            return false;
+        }
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        let newly_inserted = !std::mem::replace(&mut self.nodes[node].terminal, true);
+        if newly_inserted {
+            self.len += 1;
+        }
+        newly_inserted
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        let mut node = 0;
+        for c in word.chars() {
+            match self.nodes[node].children.get(&c) {
+                Some(&child) => node = child,
+                None => return false,
+            }
+        }
+        self.nodes[node].terminal
+    }
+
+    /// Iterate over the prefixes of `text` that are stored in the trie, shortest first.
+    pub fn prefixes_of<'a>(&'a self, text: &'a str) -> Prefixes<'a> {
+        Prefixes {
+            trie: self,
+            text,
+            chars: text.char_indices(),
+            node: Some(0),
+        }
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for Trie {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word.as_ref());
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Trie {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut trie = Self::default();
+        trie.extend(iter);
+        trie
+    }
+}
+
+/// Iterator returned by [`Trie::prefixes_of`].
+#[derive(Debug, Clone)]
+pub struct Prefixes<'a> {
+    trie: &'a Trie,
+    text: &'a str,
+    chars: CharIndices<'a>,
+    node: Option<usize>,
+}
+
+impl<'a> Iterator for Prefixes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.node?;
+            let Some((index, c)) = self.chars.next() else {
+                self.node = None;
+                return None;
+            };
+            self.node = self.trie.nodes[node].children.get(&c).copied();
+            let child = self.node?;
+            if self.trie.nodes[child].terminal {
+                return Some(&self.text[..index + c.len_utf8()]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("br"));
+        assert!(trie.insert("b"));
+        assert!(!trie.insert("br"));
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains("b"));
+        assert!(trie.contains("br"));
+        assert!(!trie.contains("bw"));
+        assert!(!trie.contains("bru"));
+    }
+
+    #[test]
+    fn prefixes() {
+        let trie: Trie = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+            .into_iter()
+            .collect();
+        assert_eq!(trie.prefixes_of("brwrr").collect::<Vec<_>>(), ["b", "br"]);
+        assert_eq!(trie.prefixes_of("bwurrg").collect::<Vec<_>>(), ["b", "bwu"]);
+        assert_eq!(trie.prefixes_of("ubwu").count(), 0);
+        assert_eq!(trie.prefixes_of("").count(), 0);
+    }
+
+    #[test]
+    fn empty_strings_are_not_stored() {
+        let mut trie = Trie::new();
+        assert!(!trie.insert(""));
+        assert!(trie.is_empty());
+        assert!(!trie.contains(""));
+
+        let trie: Trie = ["", "r"].into_iter().collect();
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.prefixes_of("rr").collect::<Vec<_>>(), ["r"]);
+    }
+}
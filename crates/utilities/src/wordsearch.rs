@@ -0,0 +1,121 @@
+//! Finding words written along straight lines in a grid of characters.
+
+use crate::maps::{EightPointCompass, Grid, Point};
+
+/// Only the four directions parallel to the edges of the grid.
+pub const ORTHOGONAL_DIRECTIONS: &[EightPointCompass] = &[
+    EightPointCompass::North,
+    EightPointCompass::East,
+    EightPointCompass::South,
+    EightPointCompass::West,
+];
+
+/// Only the directions that never read westwards or northwards, so words are never written backwards.
+pub const FORWARD_DIRECTIONS: &[EightPointCompass] = &[
+    EightPointCompass::East,
+    EightPointCompass::South,
+    EightPointCompass::SouthEast,
+];
+
+/// One occurrence of a word in the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordMatch<'w, const MAX_COORDINATE: u16> {
+    pub word: &'w str,
+    /// The point holding the first letter of the word.
+    pub start: Point<MAX_COORDINATE>,
+    pub direction: EightPointCompass,
+}
+
+impl<const MAX_COORDINATE: u16> WordMatch<'_, MAX_COORDINATE> {
+    /// Iterate over the points covered by the word, from its first letter to its last.
+    pub fn span(&self) -> impl Iterator<Item = Point<MAX_COORDINATE>> {
+        let direction = self.direction;
+        std::iter::successors(Some(self.start), move |point| point.shift(direction))
+            .take(self.word.chars().count())
+    }
+}
+
+/// Find every occurrence of each of `words` in the grid, reading in any of `directions`.
+///
+/// Matches may overlap or share letters, and each is reported separately:
+/// a word that reads the same forwards and backwards is found once in each direction.
+pub fn find_words<'w, const MAX_COORDINATE: u16>(
+    grid: &Grid<MAX_COORDINATE, char>,
+    words: &[&'w str],
+    directions: &[EightPointCompass],
+) -> Vec<WordMatch<'w, MAX_COORDINATE>> {
+    let mut matches = vec![];
+    for (&start, &letter) in grid.iter() {
+        for &word in words {
+            if !word.starts_with(letter) {
+                continue;
+            }
+            for &direction in directions {
+                let word_match = WordMatch {
+                    word,
+                    start,
+                    direction,
+                };
+                if is_written_along(grid, &word_match) {
+                    matches.push(word_match);
+                }
+            }
+        }
+    }
+    matches
+}
+
+fn is_written_along<const MAX_COORDINATE: u16>(
+    grid: &Grid<MAX_COORDINATE, char>,
+    word_match: &WordMatch<'_, MAX_COORDINATE>,
+) -> bool {
+    let mut letters = word_match.word.chars();
+    let mut span = word_match.span();
+    loop {
+        match (letters.next(), span.next()) {
+            (None, _) => return true,
+            (Some(_), None) => return false,
+            (Some(letter), Some(point)) => {
+                if grid.get(&point) != Some(&letter) {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_and_overlaps() {
+        let grid: Grid<10, char> = "\
+abab
+xxxx
+baba"
+            .parse()
+            .unwrap();
+        let point = |x, y| Point::try_from((x, y)).unwrap();
+
+        assert_eq!(find_words(&grid, &["ab"], FORWARD_DIRECTIONS).len(), 3);
+        assert_eq!(find_words(&grid, &["ab"], ORTHOGONAL_DIRECTIONS).len(), 6);
+        assert_eq!(
+            find_words(&grid, &["aba", "bab"], FORWARD_DIRECTIONS).len(),
+            4
+        );
+        assert!(find_words(&grid, &["abx"], FORWARD_DIRECTIONS).is_empty());
+
+        let matches = find_words(&grid, &["axb"], ORTHOGONAL_DIRECTIONS);
+        assert_eq!(matches.len(), 4);
+        let word_match = matches
+            .into_iter()
+            .find(|word_match| word_match.start == point(1, 1))
+            .unwrap();
+        assert_eq!(word_match.direction, EightPointCompass::South);
+        assert_eq!(
+            word_match.span().collect::<Vec<_>>(),
+            [point(1, 1), point(1, 2), point(1, 3)]
+        );
+    }
+}